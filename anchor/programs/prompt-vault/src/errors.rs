@@ -178,4 +178,187 @@ pub enum VaultError {
     
     #[msg("Address not on whitelist")]
     AddressNotOnWhitelist,
+    
+    #[msg("Invalid staking configuration")]
+    InvalidStakingConfig,
+    
+    #[msg("Stake position is still locked")]
+    StakeLocked,
+    
+    #[msg("Invalid slashing configuration")]
+    InvalidSlashingConfig,
+    
+    #[msg("Unauthorized - only admin or governance can perform this action")]
+    UnauthorizedSlashingAuthority,
+    
+    #[msg("A slash is pending for this prompt")]
+    SlashPending,
+    
+    #[msg("Dispute window has not elapsed")]
+    DisputeWindowNotElapsed,
+    
+    #[msg("Slash reason is too long (max 128 characters)")]
+    SlashReasonTooLong,
+    
+    #[msg("Emergency withdraw is only available while the vault is paused")]
+    EmergencyWithdrawUnavailable,
+    
+    #[msg("Stake account still holds stake or unclaimed rewards")]
+    StakeAccountNotEmpty,
+    
+    #[msg("Execution record is still within its retention period")]
+    RetentionPeriodNotElapsed,
+    
+    #[msg("Too many executor signing keys (max 4)")]
+    TooManySigningKeys,
+    
+    #[msg("Executor is not authorized to attest executions")]
+    UnauthorizedExecutor,
+    
+    #[msg("Invalid registry configuration")]
+    InvalidRegistryConfig,
+    
+    #[msg("Executor bond is below the minimum")]
+    ExecutorBondBelowMinimum,
+    
+    #[msg("Executor is not active")]
+    ExecutorNotActive,
+    
+    #[msg("Executor is not exiting")]
+    ExecutorNotExiting,
+    
+    #[msg("Unbonding period has not elapsed")]
+    UnbondingPeriodNotElapsed,
+    
+    #[msg("Invalid dispute configuration")]
+    InvalidDisputeConfig,
+    
+    #[msg("Execution is not open to disputes")]
+    ExecutionNotDisputable,
+    
+    #[msg("Challenge window has not elapsed")]
+    ChallengeWindowNotElapsed,
+    
+    #[msg("Execution is not settled")]
+    ExecutionNotSettled,
+    
+    #[msg("Unauthorized - only the arbiter or a validator quorum can resolve disputes before they time out")]
+    UnauthorizedArbiter,
+    
+    #[msg("Validator has already voted on this dispute")]
+    AlreadyVoted,
+    
+    #[msg("Evidence URI is too long (max 256 characters)")]
+    EvidenceUriTooLong,
+    
+    #[msg("Invalid execution ID (1-32 bytes)")]
+    InvalidExecutionId,
+    
+    #[msg("Execution nonce has already been used or is too old")]
+    ExecutionNonceReused,
+    
+    #[msg("Execution attestation has expired")]
+    AttestationExpired,
+    
+    #[msg("Invalid validator quorum")]
+    InvalidValidatorQuorum,
+    
+    #[msg("Execution is not awaiting validator attestations")]
+    ExecutionNotAwaitingQuorum,
+    
+    #[msg("Attested hashes do not match the execution record")]
+    AttestationMismatch,
+    
+    #[msg("Validator has already attested this execution")]
+    AlreadyAttested,
+    
+    #[msg("Claim validator rewards before withdrawing the bond")]
+    UnclaimedValidatorRewards,
+    
+    #[msg("Failed executions require a failure code and successful ones must not have one")]
+    InvalidFailureCode,
+    
+    #[msg("Invalid refund policy")]
+    InvalidRefundPolicy,
+    
+    #[msg("Invalid job configuration")]
+    InvalidJobConfig,
+    
+    #[msg("Job is not in the required status")]
+    InvalidJobStatus,
+    
+    #[msg("Job has expired")]
+    JobExpired,
+    
+    #[msg("Job has not expired")]
+    JobNotExpired,
+    
+    #[msg("Acceptance window has not elapsed")]
+    AcceptanceWindowNotElapsed,
+    
+    #[msg("Unauthorized - only the job's caller can perform this action")]
+    UnauthorizedCaller,
+    
+    #[msg("Execution rate limit exceeded - try again in a later slot")]
+    RateLimitExceeded,
+    
+    #[msg("Invalid metered pricing")]
+    InvalidMeteredPricing,
+    
+    #[msg("Metered prompts need a max fee to be escrowed for jobs")]
+    UnboundedExecutionFee,
+    
+    #[msg("Fee exceeds the caller's maximum")]
+    FeeExceedsMaximum,
+    
+    #[msg("Invalid integrator fee")]
+    InvalidIntegratorFee,
+    
+    #[msg("Integrator accounts do not match the execution")]
+    InvalidIntegrator,
+    
+    #[msg("Invalid discount configuration")]
+    InvalidDiscountConfig,
+    
+    #[msg("Invalid sponsor pool terms")]
+    InvalidSponsorTerms,
+    
+    #[msg("Insufficient sponsor pool balance")]
+    InsufficientSponsorBalance,
+    
+    #[msg("Sponsor accounts do not match the execution")]
+    InvalidSponsorPool,
+    
+    #[msg("Executor still has executions awaiting settlement")]
+    ExecutorHasOpenSettlements,
+    
+    #[msg("Not enough active validators to reach the quorum")]
+    ValidatorQuorumUnreachable,
+    
+    #[msg("Attestation window has not elapsed")]
+    AttestationWindowNotElapsed,
+    
+    #[msg("Prompts with a validator quorum cannot be executed as jobs")]
+    ValidatorQuorumUnsupported,
+    
+    #[msg("Callers cannot route executions through their own integrator account")]
+    SelfRoutedExecution,
+    
+    #[msg("Execution already has the maximum number of attesters")]
+    AttesterLimitReached,
+    
+    #[msg("Job is not fulfilled or its acceptance window has passed")]
+    JobNotDisputable,
+    
+    #[msg("Reward pool cannot cover the claimed rewards")]
+    InsufficientRewardPool,
+    
+    #[msg("Prompt was suspended by a slash; only the admin or governance can lift it")]
+    PromptSuspendedForCause,
+    
+    #[msg("Vault state already uses the current layout")]
+    VaultAlreadyMigrated,
+    
+    #[msg("Challengers cannot vote on their own dispute")]
+    ChallengerCannotVote,
 } 
\ No newline at end of file
@@ -0,0 +1,499 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Emitted before a stake account is closed
+#[event]
+pub struct StakeAccountClosed {
+    pub stake_account: Pubkey,
+    pub owner: Pubkey,
+    pub prompt_id: String,
+    pub position_index: u16,
+    pub rewards_earned: u64,
+    pub stake_timestamp: i64,
+    pub closed_at: i64,
+}
+
+/// Emitted before an execution record is closed so indexers keep its history
+#[event]
+pub struct ExecutionRecordClosed {
+    pub execution_record: Pubkey,
+    pub prompt_id: String,
+    pub execution_id: String,
+    pub caller: Pubkey,
+    pub payer: Pubkey,
+    pub version: String,
+    pub input_hash: [u8; 32],
+    pub output_hash: [u8; 32],
+    pub signature: [u8; 64],
+    pub execution_time_ms: u64,
+    pub success: bool,
+    pub timestamp: i64,
+    pub closed_at: i64,
+}
+
+/// Emitted when the vault is initialized
+#[event]
+pub struct VaultInitialized {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub payment_mint: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub creator_share_bps: u16,
+    pub validator_share_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a legacy vault state is migrated to the current layout
+#[event]
+pub struct VaultMigrated {
+    pub admin: Pubkey,
+    pub payment_mint: Pubkey,
+    pub previous_size: u64,
+    pub new_size: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the admin updates vault configuration
+#[event]
+pub struct VaultConfigUpdated {
+    pub admin: Pubkey,
+    pub staking_config_updated: bool,
+    pub slashing_config_updated: bool,
+    pub retention_period_updated: bool,
+    pub registry_config_updated: bool,
+    pub dispute_config_updated: bool,
+    pub job_config_updated: bool,
+    pub price_notice_period_updated: bool,
+    pub integrator_config_updated: bool,
+    pub discount_config_updated: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the vault is paused
+#[event]
+pub struct VaultPaused {
+    pub admin: Pubkey,
+    pub paused_at: i64,
+}
+
+/// Emitted when the vault resumes operations
+#[event]
+pub struct VaultResumed {
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt is registered
+#[event]
+pub struct PromptRegistered {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub author: Pubkey,
+    pub version: String,
+    pub metadata_uri: String,
+    pub content_hash: [u8; 32],
+    pub license_type: LicenseType,
+    pub fee_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt version is published
+#[event]
+pub struct VersionCreated {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub version: String,
+    pub metadata_uri: String,
+    pub content_hash: [u8; 32],
+    pub version_count: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt's metadata URI or tags change
+#[event]
+pub struct MetadataUpdated {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub metadata_uri: String,
+    pub tags: Vec<PromptTag>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt's license terms change
+#[event]
+pub struct LicenseUpdated {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub license_type: LicenseType,
+    pub fee_amount: u64,
+    pub token_gate: Option<Pubkey>,
+    pub validator_quorum: u8,
+    pub refund_policy: RefundPolicy,
+    pub metered_pricing: MeteredPricing,
+    pub pending_price_change: Option<PendingPriceChange>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt's status changes
+#[event]
+pub struct PromptStatusChanged {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub previous_status: PromptStatus,
+    pub new_status: PromptStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt changes owner
+#[event]
+pub struct OwnershipTransferred {
+    pub prompt: Pubkey,
+    pub prompt_id: String,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a prompt is forked
+#[event]
+pub struct PromptForked {
+    pub original_prompt: Pubkey,
+    pub original_prompt_id: String,
+    pub new_prompt: Pubkey,
+    pub new_prompt_id: String,
+    pub forker: Pubkey,
+    pub license_type: LicenseType,
+    pub fee_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when tokens are staked on a position, new or existing
+#[event]
+pub struct Staked {
+    pub stake_account: Pubkey,
+    pub prompt_id: String,
+    pub staker: Pubkey,
+    pub position_index: u16,
+    pub amount: u64,
+    pub position_staked: u64,
+    pub prompt_total_staked: u64,
+    pub lockup_end: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when tokens are unstaked from a position
+#[event]
+pub struct Unstaked {
+    pub stake_account: Pubkey,
+    pub prompt_id: String,
+    pub staker: Pubkey,
+    pub position_index: u16,
+    pub amount: u64,
+    pub position_staked: u64,
+    pub prompt_total_staked: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a staker withdraws principal while the vault is paused
+#[event]
+pub struct EmergencyWithdrawn {
+    pub stake_account: Pubkey,
+    pub prompt_id: String,
+    pub staker: Pubkey,
+    pub position_index: u16,
+    pub amount: u64,
+    pub forfeited_rewards: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when staking rewards are claimed
+#[event]
+pub struct RewardsClaimed {
+    pub stake_account: Pubkey,
+    pub prompt_id: String,
+    pub staker: Pubkey,
+    pub position_index: u16,
+    pub amount: u64,
+    pub rewards_earned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a slash is proposed against a prompt's stake
+#[event]
+pub struct SlashProposed {
+    pub slash_proposal: Pubkey,
+    pub prompt_id: String,
+    pub proposer: Pubkey,
+    pub slash_bps: u16,
+    pub destination: SlashDestination,
+    pub reason: String,
+    pub executable_at: i64,
+}
+
+/// Emitted when a pending slash is cancelled
+#[event]
+pub struct SlashCancelled {
+    pub prompt_id: String,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a slash is executed
+#[event]
+pub struct SlashExecuted {
+    pub prompt_id: String,
+    pub slashed_amount: u64,
+    pub destination: SlashDestination,
+    pub remaining_stake: u64,
+    pub slash_factor: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an executor or validator registers
+#[event]
+pub struct ExecutorRegistered {
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub role: ExecutorRole,
+    pub bond_amount: u64,
+    pub signing_keys: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when an executor updates its keys, metadata or bond
+#[event]
+pub struct ExecutorUpdated {
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub signing_keys: Vec<Pubkey>,
+    pub bond_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an executor is jailed, unjailed or starts exiting
+#[event]
+pub struct ExecutorStatusChanged {
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub previous_status: ExecutorStatus,
+    pub new_status: ExecutorStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when an exited executor withdraws its bond
+#[event]
+pub struct ExecutorBondWithdrawn {
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a validator claims its attestation rewards
+#[event]
+pub struct ValidatorRewardsClaimed {
+    pub executor: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an execution is recorded, with the fee it was charged
+#[event]
+pub struct ExecutionRecorded {
+    pub execution_record: Pubkey,
+    pub prompt_id: String,
+    pub execution_id: String,
+    pub caller: Pubkey,
+    pub executor: Pubkey,
+    pub version: String,
+    pub input_hash: [u8; 32],
+    pub output_hash: [u8; 32],
+    pub execution_time_ms: u64,
+    pub success: bool,
+    pub failure_code: Option<FailureCode>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub integrator: Option<Pubkey>,
+    pub discount_amount: u64,
+    pub sponsor_pool: Option<Pubkey>,
+    pub refunded_amount: u64,
+    pub fee_breakdown: FeeBreakdown,
+    pub status: ExecutionStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when a validator attests an execution awaiting quorum
+#[event]
+pub struct ExecutionAttested {
+    pub execution_record: Pubkey,
+    pub validator: Pubkey,
+    pub attestations: u8,
+    pub required_attestations: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an execution's fee is refunded after its quorum missed the deadline
+#[event]
+pub struct UnattestedExecutionRefunded {
+    pub execution_record: Pubkey,
+    pub prompt_id: String,
+    pub refund_amount: u64,
+    pub attestations: u8,
+    pub required_attestations: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an escrowed execution fee is released
+#[event]
+pub struct ExecutionFinalized {
+    pub execution_record: Pubkey,
+    pub prompt_id: String,
+    pub fee_breakdown: FeeBreakdown,
+    pub timestamp: i64,
+}
+
+/// Emitted when an execution is disputed
+#[event]
+pub struct DisputeOpened {
+    pub dispute: Pubkey,
+    pub execution_record: Pubkey,
+    pub challenger: Pubkey,
+    pub bond_amount: u64,
+    pub evidence_uri: String,
+    pub resolve_deadline: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a validator votes on a dispute
+#[event]
+pub struct DisputeVoteCast {
+    pub dispute: Pubkey,
+    pub validator: Pubkey,
+    pub uphold: bool,
+    pub uphold_votes: u8,
+    pub reject_votes: u8,
+}
+
+/// Emitted when a dispute is resolved
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub execution_record: Pubkey,
+    pub resolver: Pubkey,
+    pub upheld: bool,
+    pub fee_amount: u64,
+    pub bond_amount: u64,
+    pub executor_slashed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a caller opens an execution job
+#[event]
+pub struct JobOpened {
+    pub job: Pubkey,
+    pub prompt_id: String,
+    pub job_id: String,
+    pub caller: Pubkey,
+    pub input_hash: [u8; 32],
+    pub escrowed_amount: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an executor claims a job
+#[event]
+pub struct JobClaimed {
+    pub job: Pubkey,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an executor submits a job's output
+#[event]
+pub struct JobFulfilled {
+    pub job: Pubkey,
+    pub executor: Pubkey,
+    pub output_hash: [u8; 32],
+    pub execution_time_ms: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub success: bool,
+    pub failure_code: Option<FailureCode>,
+    pub fee_breakdown: FeeBreakdown,
+    pub acceptance_deadline: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a fulfilled job's fee is released
+#[event]
+pub struct JobSettled {
+    pub job: Pubkey,
+    pub prompt_id: String,
+    pub accepted: bool,
+    pub fee_breakdown: FeeBreakdown,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a caller disputes a fulfilled job, moving it to an execution record
+#[event]
+pub struct JobDisputed {
+    pub job: Pubkey,
+    pub execution_record: Pubkey,
+    pub caller: Pubkey,
+    pub executor: Pubkey,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an expired job's escrow is returned to the caller
+#[event]
+pub struct JobRefunded {
+    pub job: Pubkey,
+    pub caller: Pubkey,
+    pub refunded_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an integrator registers
+#[event]
+pub struct IntegratorRegistered {
+    pub integrator: Pubkey,
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when an integrator changes its requested fee
+#[event]
+pub struct IntegratorUpdated {
+    pub integrator: Pubkey,
+    pub authority: Pubkey,
+    pub fee_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a sponsor funds a pool or changes its terms
+#[event]
+pub struct SponsorPoolFunded {
+    pub sponsor_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub prompt_id: String,
+    pub amount: u64,
+    pub balance: u64,
+    pub per_user_cap: Option<u64>,
+    pub allowlist_size: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a sponsor withdraws from a pool
+#[event]
+pub struct SponsorPoolWithdrawn {
+    pub sponsor_pool: Pubkey,
+    pub sponsor: Pubkey,
+    pub prompt_id: String,
+    pub amount: u64,
+    pub balance: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Transfer};
+use crate::state::{ExecutionRecord, ExecutorAccount, FeeBreakdown, IntegratorAccount, SponsorPool, SponsorUsage};
+
+/// Token accounts receiving the shares of an execution fee
+pub struct FeeRecipients<'a, 'info> {
+    pub author_token_account: &'a AccountInfo<'info>,
+    pub treasury_token_account: &'a AccountInfo<'info>,
+    pub validator_pool: &'a AccountInfo<'info>,
+    pub payment_mint: &'a AccountInfo<'info>,
+    /// Required when the breakdown carries an integrator share
+    pub integrator_token_account: Option<&'a AccountInfo<'info>>,
+}
+
+/// Sponsor accounts a refunded sponsored fee is returned to
+pub struct SponsorRefund<'a, 'info> {
+    pub sponsor_pool: &'a mut SponsorPool,
+    pub sponsor_usage: &'a mut SponsorUsage,
+    pub sponsor_escrow: &'a AccountInfo<'info>,
+}
+
+/// Transfer tokens, signing with `signer_seeds` when the authority is a PDA
+pub fn transfer_tokens<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Release each attesting validator's open settlement on an execution,
+/// crediting an equal part of `amount` to their pending rewards
+///
+/// `accounts` must hold the attesters' executor accounts in the order they
+/// attested. The tokens themselves already sit in the validator pool; refunds
+/// pass an `amount` of zero.
+pub fn settle_attesters(attesters: &[Pubkey], accounts: &[AccountInfo], amount: u64) -> Result<()> {
+    if attesters.is_empty() {
+        return Ok(());
+    }
+    require!(
+        accounts.len() >= attesters.len(),
+        crate::errors::VaultError::InvalidAccountData
+    );
+    
+    let share = amount / attesters.len() as u64;
+    for (attester, info) in attesters.iter().zip(accounts.iter()) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"executor", attester.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            info.key() == expected && info.owner == &crate::ID && info.is_writable,
+            crate::errors::VaultError::InvalidAccountData
+        );
+        
+        let mut data = info.try_borrow_mut_data()?;
+        let mut validator = ExecutorAccount::try_deserialize(&mut &data[..])?;
+        validator.pending_rewards = validator.pending_rewards.saturating_add(share);
+        validator.close_settlement();
+        validator.try_serialize(&mut &mut data[..])?;
+    }
+    
+    msg!("Credited {} to each of {} attesting validators", share, attesters.len());
+    
+    Ok(())
+}
+
+/// Pay out each share of an execution fee from `from`
+pub fn distribute_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    recipients: &FeeRecipients<'_, 'info>,
+    breakdown: &FeeBreakdown,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    // Transfer to creator
+    transfer_tokens(
+        token_program,
+        from,
+        recipients.author_token_account,
+        authority,
+        breakdown.creator_amount,
+        signer_seeds,
+    )?;
+    
+    // Transfer to DAO treasury
+    transfer_tokens(
+        token_program,
+        from,
+        recipients.treasury_token_account,
+        authority,
+        breakdown.dao_amount,
+        signer_seeds,
+    )?;
+    
+    // Transfer to validator pool
+    transfer_tokens(
+        token_program,
+        from,
+        recipients.validator_pool,
+        authority,
+        breakdown.validator_amount,
+        signer_seeds,
+    )?;
+    
+    // Transfer to the integrator that routed the execution
+    if breakdown.integrator_amount > 0 {
+        let integrator_token_account = recipients.integrator_token_account
+            .ok_or(crate::errors::VaultError::InvalidIntegrator)?;
+        transfer_tokens(
+            token_program,
+            from,
+            integrator_token_account,
+            authority,
+            breakdown.integrator_amount,
+            signer_seeds,
+        )?;
+    }
+    
+    // Burn tokens for deflationary mechanism
+    if breakdown.burn_amount > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Burn {
+                    mint: recipients.payment_mint.clone(),
+                    from: from.clone(),
+                    authority: authority.clone(),
+                },
+                signer_seeds,
+            ),
+            breakdown.burn_amount,
+        )?;
+    }
+    
+    msg!("Fee distribution - Creator: {}, DAO: {}, Validators: {}, Integrator: {}, Burned: {}",
+         breakdown.creator_amount, breakdown.dao_amount,
+         breakdown.validator_amount, breakdown.integrator_amount, breakdown.burn_amount);
+    
+    Ok(())
+}
+
+/// Add a paid integrator share to the integrator's totals
+pub fn credit_integrator(integrator: Option<&mut IntegratorAccount>, amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    let integrator = integrator.ok_or(crate::errors::VaultError::InvalidIntegrator)?;
+    integrator.record_fee(amount);
+    Ok(())
+}
+
+/// Refund an execution's escrowed fee to whoever paid it
+///
+/// The sponsored part goes back to the pool and off the user's draw on it;
+/// the caller gets the rest.
+pub fn refund_execution_fee<'info>(
+    token_program: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    escrow_seeds: &[&[&[u8]]],
+    record: &ExecutionRecord,
+    caller_token_account: &AccountInfo<'info>,
+    sponsor: Option<SponsorRefund<'_, 'info>>,
+) -> Result<()> {
+    let sponsored_amount = record.sponsored_amount;
+    if record.sponsor_pool.is_some() {
+        let sponsor = sponsor.ok_or(crate::errors::VaultError::InvalidSponsorPool)?;
+        sponsor.sponsor_pool.credit_refund(sponsored_amount);
+        sponsor.sponsor_usage.credit_refund(sponsored_amount);
+        transfer_tokens(
+            token_program,
+            escrow,
+            sponsor.sponsor_escrow,
+            escrow,
+            sponsored_amount,
+            escrow_seeds,
+        )?;
+    }
+    
+    transfer_tokens(
+        token_program,
+        escrow,
+        caller_token_account,
+        escrow,
+        record.caller_refund_amount(),
+        escrow_seeds,
+    )
+}
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{self, FeeRecipients};
+use crate::events::JobSettled;
+
+/// Accounts for releasing a fulfilled job's escrow, shared by
+/// `accept_job` and `finalize_job`
+#[derive(Accounts)]
+pub struct SettleJob<'info> {
+    #[account(
+        mut,
+        constraint = job.status == JobStatus::Fulfilled @ VaultError::InvalidJobStatus,
+        close = caller
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    /// CHECK: Caller receives the job account rent
+    #[account(
+        mut,
+        constraint = caller.key() == job.caller @ VaultError::UnauthorizedCaller
+    )]
+    pub caller: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", job.executor.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PromptAnalytics::SPACE,
+        seeds = [b"analytics", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_analytics: Box<Account<'info, PromptAnalytics>>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == job.caller
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = author_token_account.owner == prompt_data.author
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == vault_state.treasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Validator pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"validator_pool"],
+        bump
+    )]
+    pub validator_pool: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        address = vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub payment_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund the unused escrow, distribute the fee and record the execution
+pub fn settle(ctx: Context<SettleJob>, accepted: bool) -> Result<()> {
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let escrow = ctx.accounts.execution_escrow.clone();
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    let job = &mut ctx.accounts.job;
+    let fee_breakdown = job.fee_breakdown.clone();
+    let refunded_amount = job.refund_amount();
+    
+    if refunded_amount > 0 {
+        fees::transfer_tokens(
+            &token_program,
+            &escrow,
+            &ctx.accounts.caller_token_account.to_account_info(),
+            &escrow,
+            refunded_amount,
+            escrow_seeds,
+        )?;
+    }
+    
+    fees::distribute_fee(
+        &token_program,
+        &escrow,
+        &escrow,
+        &FeeRecipients {
+            author_token_account: &ctx.accounts.author_token_account.to_account_info(),
+            treasury_token_account: &ctx.accounts.treasury_token_account.to_account_info(),
+            validator_pool: &ctx.accounts.validator_pool,
+            payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+            integrator_token_account: None,
+        },
+        &fee_breakdown,
+        escrow_seeds,
+    )?;
+    
+    job.status = JobStatus::Settled;
+    ctx.accounts.executor.close_settlement();
+    
+    // Update prompt statistics
+    ctx.accounts.prompt_data.record_execution(
+        job.execution_time_ms,
+        job.success,
+        job.failure_code,
+    );
+    
+    // Update windowed analytics
+    let prompt_analytics = &mut ctx.accounts.prompt_analytics;
+    if !prompt_analytics.is_initialized() {
+        prompt_analytics.initialize(job.prompt_id.clone());
+    }
+    prompt_analytics.record_execution(job.success, job.execution_time_ms);
+    if job.success {
+        let revenue = fee_breakdown.revenue();
+        ctx.accounts.prompt_data.record_revenue(revenue);
+        prompt_analytics.record_revenue(revenue);
+    }
+    
+    emit!(JobSettled {
+        job: job.key(),
+        prompt_id: job.prompt_id.clone(),
+        accepted,
+        fee_breakdown: fee_breakdown.clone(),
+        refunded_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Job {} settled - fee: {}, refunded: {}",
+         job.job_id, fee_breakdown.total_fee, refunded_amount);
+    
+    Ok(())
+}
+
+pub fn handler(ctx: Context<SettleJob>) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.job.caller,
+        VaultError::UnauthorizedCaller
+    );
+    
+    settle(ctx, true)
+}
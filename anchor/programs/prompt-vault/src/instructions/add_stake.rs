@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::Staked;
+
+#[derive(Accounts)]
+#[instruction(position_index: u16)]
+pub struct AddStake<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"stake",
+            prompt_data.id.as_bytes(),
+            staker.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump,
+        constraint = stake_account.owner == staker.key() @ VaultError::UnauthorizedAuthor
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump,
+        constraint = prompt_data.is_active() @ VaultError::PromptNotActive
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = staker_token_account.owner == staker.key()
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Stake pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump
+    )]
+    pub stake_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<AddStake>, _position_index: u16, amount: u64) -> Result<()> {
+    let stake_account = &mut ctx.accounts.stake_account;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    let staking_config = &ctx.accounts.vault_state.staking_config;
+    
+    require!(amount > 0, VaultError::StakeAmountBelowMinimum);
+    require!(
+        ctx.accounts.staker_token_account.amount >= amount,
+        VaultError::InsufficientStake
+    );
+    
+    // Settle rewards earned at the old, pre-slash balance before it changes
+    stake_account.settle_rewards(staking_config);
+    stake_account.apply_slashing(prompt_data.slash_factor);
+    
+    let new_total = stake_account
+        .staked_amount
+        .checked_add(amount)
+        .ok_or(VaultError::ArithmeticOverflow)?;
+    staking_config.check_stake_amount(new_total)?;
+    
+    stake_account.add_stake(amount);
+    prompt_data.total_staked = prompt_data.total_staked.saturating_add(amount);
+    
+    // Transfer tokens to stake pool
+    let transfer_to_pool = Transfer {
+        from: ctx.accounts.staker_token_account.to_account_info(),
+        to: ctx.accounts.stake_pool.to_account_info(),
+        authority: ctx.accounts.staker.to_account_info(),
+    };
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_to_pool,
+        ),
+        amount,
+    )?;
+    
+    emit!(Staked {
+        stake_account: stake_account.key(),
+        prompt_id: stake_account.prompt_id.clone(),
+        staker: stake_account.owner,
+        position_index: stake_account.position_index,
+        amount,
+        position_staked: stake_account.staked_amount,
+        prompt_total_staked: prompt_data.total_staked,
+        lockup_end: stake_account.lockup_end,
+        timestamp: stake_account.last_deposit,
+    });
+    
+    msg!("Added {} tokens to position {} on prompt: {}", amount, stake_account.position_index, stake_account.prompt_id);
+    msg!("Total stake: {}, pending rewards: {}", stake_account.staked_amount, stake_account.pending_rewards);
+    
+    Ok(())
+}
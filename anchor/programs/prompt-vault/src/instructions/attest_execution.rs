@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutionAttested;
+use crate::utils;
+
+#[derive(Accounts)]
+pub struct AttestExecution<'info> {
+    #[account(
+        mut,
+        constraint = execution_record.status == ExecutionStatus::AwaitingQuorum @ VaultError::ExecutionNotAwaitingQuorum
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = validator.can_validate() @ VaultError::ExecutorNotActive,
+        constraint = validator.authority != execution_record.executor @ VaultError::UnauthorizedExecutor
+    )]
+    pub validator: Account<'info, ExecutorAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar used to inspect the Ed25519 attestation
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<AttestExecution>, params: AttestExecutionParams) -> Result<()> {
+    let record_key = ctx.accounts.execution_record.key();
+    let execution_record = &mut ctx.accounts.execution_record;
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Past the deadline the fee is refundable instead
+    require!(!execution_record.is_attestation_overdue(now), VaultError::AttestationExpired);
+    
+    // Validators must independently arrive at the recorded result
+    require!(
+        execution_record.input_hash == params.input_hash &&
+            execution_record.output_hash == params.output_hash,
+        VaultError::AttestationMismatch
+    );
+    
+    // Verify the validator's Ed25519 signature over the result, carried in this transaction
+    let validator = &mut ctx.accounts.validator;
+    require!(
+        validator.has_signing_key(&params.signing_key),
+        VaultError::UnauthorizedExecutor
+    );
+    let signing_message = params.signing_message(&record_key, &validator.authority);
+    let attested = utils::load_ed25519_signatures(&ctx.accounts.instructions)?
+        .iter()
+        .any(|entry| {
+            entry.pubkey == params.signing_key &&
+                entry.signature == params.signature &&
+                entry.message == signing_message
+        });
+    require!(attested, VaultError::InvalidExecutionSignature);
+    
+    let quorum_reached = execution_record.add_attester(validator.authority)?;
+    validator.executions_attested = validator.executions_attested.saturating_add(1);
+    validator.open_settlement();
+    validator.touch();
+    
+    if quorum_reached {
+        // Open the challenge window; finalize_execution releases the fee after it
+        let challenge_window = ctx.accounts.vault_state.dispute_config.challenge_window;
+        execution_record.open_challenge_window(challenge_window, now);
+    }
+    
+    emit!(ExecutionAttested {
+        execution_record: execution_record.key(),
+        validator: validator.authority,
+        attestations: execution_record.attesters.len() as u8,
+        required_attestations: execution_record.required_attestations,
+        timestamp: validator.last_updated,
+    });
+    
+    msg!("Execution for prompt {} attested by validator: {}",
+         execution_record.prompt_id, validator.authority);
+    msg!("Attestations: {}/{}", execution_record.attesters.len(), execution_record.required_attestations);
+    
+    Ok(())
+}
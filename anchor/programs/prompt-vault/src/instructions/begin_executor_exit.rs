@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutorStatusChanged;
+
+#[derive(Accounts)]
+pub struct BeginExecutorExit<'info> {
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = executor.authority == authority.key() @ VaultError::UnauthorizedExecutor
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<BeginExecutorExit>) -> Result<()> {
+    let executor = &mut ctx.accounts.executor;
+    
+    // Jailed executors stay bonded until the admin reinstates them
+    require!(
+        executor.status == ExecutorStatus::Active,
+        VaultError::InvalidStatusTransition
+    );
+    
+    let previous_status = executor.status;
+    let was_validating = executor.can_validate();
+    executor.status = ExecutorStatus::Exiting;
+    executor.exit_requested_at = Clock::get()?.unix_timestamp;
+    executor.touch();
+    ctx.accounts.vault_state.update_active_validators(was_validating, executor.can_validate());
+    
+    emit!(ExecutorStatusChanged {
+        executor: executor.key(),
+        authority: executor.authority,
+        previous_status,
+        new_status: executor.status,
+        timestamp: executor.exit_requested_at,
+    });
+    
+    msg!("Executor exit started: {}", executor.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::SlashCancelled;
+
+#[derive(Accounts)]
+pub struct CancelSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"slash", prompt_data.id.as_bytes()],
+        bump,
+        close = authority
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_slashing_authority(&authority.key()) @ VaultError::UnauthorizedSlashingAuthority
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelSlash>) -> Result<()> {
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    
+    // A cancelled slash withdraws the cause, handing the status back to the author
+    prompt_data.slash_pending = false;
+    prompt_data.suspended_for_cause = false;
+    prompt_data.touch();
+    
+    emit!(SlashCancelled {
+        prompt_id: prompt_data.id.clone(),
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: prompt_data.last_updated,
+    });
+    
+    msg!("Slash cancelled for prompt: {}", prompt_data.id);
+    msg!("Cancelled by: {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
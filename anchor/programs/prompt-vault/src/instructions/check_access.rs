@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(prompt_id: String)]
+pub struct CheckAccess<'info> {
+    #[account(
+        seeds = [b"prompt", prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    /// CHECK: User whose access is checked; does not need to sign
+    pub user: UncheckedAccount<'info>,
+    
+    /// User's payment token account, needed to evaluate token-gated access
+    #[account(
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: User's usage of the prompt - validated by seeds, empty if the user has never executed it
+    #[account(
+        seeds = [b"usage", prompt_id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub user_usage: UncheckedAccount<'info>,
+}
+
+/// Read-only; other programs CPI into this and read the decision from return data
+pub fn handler(ctx: Context<CheckAccess>, _prompt_id: String) -> Result<AccessDecision> {
+    let vault_state = &ctx.accounts.vault_state;
+    
+    let user_token_balance = ctx.accounts.user_token_account
+        .as_ref()
+        .filter(|account| account.mint == vault_state.payment_mint)
+        .map_or(0, |account| account.amount);
+    let user_usage = UserUsage::load_at(&ctx.accounts.user_usage)?;
+    
+    let mut decision = ctx.accounts.prompt_data.access_decision(
+        &ctx.accounts.user.key(),
+        user_token_balance,
+        user_usage.as_ref(),
+    );
+    decision.granted = decision.granted && vault_state.is_operational();
+    
+    msg!("Access check for prompt {}: {}", decision.prompt_id, decision.granted);
+    
+    Ok(decision)
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::JobClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimJob<'info> {
+    #[account(
+        mut,
+        constraint = job.status == JobStatus::Open @ VaultError::InvalidJobStatus,
+        constraint = !job.is_expired(Clock::get()?.unix_timestamp) @ VaultError::JobExpired
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = executor.can_execute() @ VaultError::ExecutorNotActive
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimJob>) -> Result<()> {
+    let job = &mut ctx.accounts.job;
+    let executor = ctx.accounts.authority.key();
+    
+    job.executor = Some(executor);
+    job.status = JobStatus::Claimed;
+    
+    // The escrow stays open against the executor's bond until the job settles
+    ctx.accounts.executor.open_settlement();
+    ctx.accounts.executor.touch();
+    
+    emit!(JobClaimed {
+        job: job.key(),
+        executor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Job {} claimed by executor: {}", job.job_id, executor);
+    
+    Ok(())
+}
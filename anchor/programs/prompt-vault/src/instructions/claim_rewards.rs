@@ -1,16 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Token, TokenAccount};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::RewardsClaimed;
+use crate::fees;
 
 #[derive(Accounts)]
+#[instruction(position_index: u16)]
 pub struct ClaimRewards<'info> {
     #[account(
         mut,
         seeds = [
             b"stake",
             prompt_data.id.as_bytes(),
-            staker.key().as_ref()
+            staker.key().as_ref(),
+            &position_index.to_le_bytes()
         ],
         bump,
         constraint = stake_account.owner == staker.key() @ VaultError::UnauthorizedAuthor
@@ -39,38 +43,64 @@ pub struct ClaimRewards<'info> {
     )]
     pub staker_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: Reward pool account - validated by seeds
     #[account(
         mut,
         seeds = [b"reward_pool"],
         bump
     )]
-    pub reward_pool: AccountInfo<'info>,
+    pub reward_pool: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
+pub fn handler(ctx: Context<ClaimRewards>, _position_index: u16) -> Result<()> {
     let stake_account = &mut ctx.accounts.stake_account;
+    let staking_config = &ctx.accounts.vault_state.staking_config;
     
     // Check if eligible for rewards (minimum stake duration)
     require!(
-        stake_account.is_eligible_for_rewards(86400), // 24 hours minimum
+        stake_account.is_eligible_for_rewards(staking_config.min_stake_duration),
         VaultError::MinimumStakeDurationNotMet
     );
     
-    // Calculate pending rewards
-    let reward_rate_per_second = 100; // Example rate
-    let pending_rewards = stake_account.calculate_pending_rewards(reward_rate_per_second);
+    // Settle rewards at the pre-slash balance; they only accrue on what survives from here on
+    stake_account.settle_rewards(staking_config);
+    stake_account.apply_slashing(ctx.accounts.prompt_data.slash_factor);
+    
+    // Pending rewards, including any settled on earlier top-ups
+    let pending_rewards = stake_account.pending_rewards;
     
     require!(pending_rewards > 0, VaultError::NoRewardsAvailable);
+    require!(
+        ctx.accounts.reward_pool.amount >= pending_rewards,
+        VaultError::InsufficientRewardPool
+    );
     
     // Claim rewards
     stake_account.claim_rewards(pending_rewards);
     
-    // Transfer rewards from pool
-    // Note: In a full implementation, this would use a PDA signer
-    // For now, we'll just log the transfer
+    emit!(RewardsClaimed {
+        stake_account: stake_account.key(),
+        prompt_id: stake_account.prompt_id.clone(),
+        staker: stake_account.owner,
+        position_index: stake_account.position_index,
+        amount: pending_rewards,
+        rewards_earned: stake_account.rewards_earned,
+        timestamp: stake_account.last_claim,
+    });
+    
+    // Pay the rewards out of the reward pool
+    let reward_pool_bump = [ctx.bumps.reward_pool];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"reward_pool", &reward_pool_bump]];
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.reward_pool.to_account_info(),
+        &ctx.accounts.staker_token_account.to_account_info(),
+        &ctx.accounts.reward_pool.to_account_info(),
+        pending_rewards,
+        signer_seeds,
+    )?;
+    
     msg!("Claimed {} reward tokens for prompt: {}", pending_rewards, stake_account.prompt_id);
     msg!("Total rewards earned: {}", stake_account.rewards_earned);
     
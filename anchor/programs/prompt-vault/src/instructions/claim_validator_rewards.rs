@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::ValidatorRewardsClaimed;
+
+#[derive(Accounts)]
+pub struct ClaimValidatorRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = validator.authority == authority.key() @ VaultError::UnauthorizedExecutor
+    )]
+    pub validator: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Validator pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"validator_pool"],
+        bump
+    )]
+    pub validator_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimValidatorRewards>) -> Result<()> {
+    let rewards = ctx.accounts.validator.pending_rewards;
+    require!(rewards > 0, VaultError::NoRewardsAvailable);
+    
+    let validator_pool_bump = [ctx.bumps.validator_pool];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"validator_pool", &validator_pool_bump]];
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.validator_pool,
+        &ctx.accounts.authority_token_account.to_account_info(),
+        &ctx.accounts.validator_pool,
+        rewards,
+        signer_seeds,
+    )?;
+    
+    let validator = &mut ctx.accounts.validator;
+    validator.pending_rewards = 0;
+    validator.touch();
+    
+    emit!(ValidatorRewardsClaimed {
+        executor: validator.key(),
+        authority: validator.authority,
+        amount: rewards,
+        timestamp: validator.last_updated,
+    });
+    
+    msg!("Validator rewards claimed: {} tokens by {}", rewards, validator.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutionRecordClosed;
+
+#[derive(Accounts)]
+pub struct CloseExecutionRecord<'info> {
+    #[account(
+        mut,
+        seeds = [
+            execution_record.seed_prefix(),
+            execution_record.prompt_id.as_bytes(),
+            execution_record.caller.as_ref(),
+            execution_record.execution_id.as_bytes()
+        ],
+        bump,
+        constraint = execution_record.is_past_retention(vault_state.execution_retention_period)
+            @ VaultError::RetentionPeriodNotElapsed,
+        constraint = execution_record.is_settled() @ VaultError::ExecutionNotSettled,
+        close = payer
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    /// CHECK: Original rent payer - validated against the record
+    #[account(
+        mut,
+        constraint = payer.key() == execution_record.payer @ VaultError::InvalidAccountData
+    )]
+    pub payer: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<CloseExecutionRecord>) -> Result<()> {
+    let execution_record = &ctx.accounts.execution_record;
+    
+    emit!(ExecutionRecordClosed {
+        execution_record: execution_record.key(),
+        prompt_id: execution_record.prompt_id.clone(),
+        execution_id: execution_record.execution_id.clone(),
+        caller: execution_record.caller,
+        payer: execution_record.payer,
+        version: execution_record.version.clone(),
+        input_hash: execution_record.input_hash,
+        output_hash: execution_record.output_hash,
+        signature: execution_record.signature,
+        execution_time_ms: execution_record.execution_time_ms,
+        success: execution_record.success,
+        timestamp: execution_record.timestamp,
+        closed_at: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Execution record closed for prompt: {}", execution_record.prompt_id);
+    msg!("Rent returned to: {}", execution_record.payer);
+    
+    Ok(())
+}
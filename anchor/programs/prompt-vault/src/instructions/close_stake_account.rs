@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::StakeAccountClosed;
+
+#[derive(Accounts)]
+#[instruction(position_index: u16)]
+pub struct CloseStakeAccount<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"stake",
+            prompt_data.id.as_bytes(),
+            staker.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump,
+        constraint = stake_account.owner == staker.key() @ VaultError::UnauthorizedAuthor,
+        close = staker
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(mut)]
+    pub staker: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseStakeAccount>, _position_index: u16) -> Result<()> {
+    let stake_account = &mut ctx.accounts.stake_account;
+    
+    stake_account.apply_slashing(ctx.accounts.prompt_data.slash_factor);
+    require!(
+        stake_account.staked_amount == 0 && stake_account.pending_rewards == 0,
+        VaultError::StakeAccountNotEmpty
+    );
+    
+    emit!(StakeAccountClosed {
+        stake_account: stake_account.key(),
+        owner: stake_account.owner,
+        prompt_id: stake_account.prompt_id.clone(),
+        position_index: stake_account.position_index,
+        rewards_earned: stake_account.rewards_earned,
+        stake_timestamp: stake_account.stake_timestamp,
+        closed_at: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Stake account closed for prompt: {} (position {})", stake_account.prompt_id, stake_account.position_index);
+    
+    Ok(())
+}
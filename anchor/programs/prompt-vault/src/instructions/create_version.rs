@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::VersionCreated;
 
 #[derive(Accounts)]
 #[instruction(params: CreateVersionParams)]
@@ -39,6 +40,16 @@ pub fn handler(ctx: Context<CreateVersion>, params: CreateVersionParams) -> Resu
     // Add version to prompt
     prompt_data.add_version(new_version)?;
     
+    emit!(VersionCreated {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        version: prompt_data.current_version.clone(),
+        metadata_uri: prompt_data.metadata_uri.clone(),
+        content_hash: params.content_hash,
+        version_count: prompt_data.version_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
     msg!("New version created for prompt: {}", params.prompt_id);
     msg!("Version: {}", params.version);
     
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::{DisputeOpened, JobDisputed};
+
+#[derive(Accounts)]
+pub struct DisputeJob<'info> {
+    #[account(
+        mut,
+        constraint = job.is_disputable(Clock::get()?.unix_timestamp) @ VaultError::JobNotDisputable,
+        close = caller
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    #[account(
+        init,
+        payer = caller,
+        space = ExecutionRecord::SPACE,
+        seeds = [
+            b"job_execution",
+            job.prompt_id.as_bytes(),
+            caller.key().as_ref(),
+            job.job_id.as_bytes()
+        ],
+        bump
+    )]
+    pub execution_record: Box<Account<'info, ExecutionRecord>>,
+    
+    #[account(
+        init,
+        payer = caller,
+        space = Dispute::SPACE,
+        seeds = [b"dispute", execution_record.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PromptAnalytics::SPACE,
+        seeds = [b"analytics", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_analytics: Box<Account<'info, PromptAnalytics>>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(
+        mut,
+        constraint = caller.key() == job.caller @ VaultError::UnauthorizedCaller
+    )]
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == caller.key()
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Reject a fulfilled job by moving it into the execution dispute flow
+///
+/// The job becomes a disputed execution record holding the charged fee, so
+/// `vote_on_dispute` and `resolve_dispute` settle it like any other record.
+pub fn handler(ctx: Context<DisputeJob>, evidence_uri: String) -> Result<()> {
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let escrow = ctx.accounts.execution_escrow.clone();
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    let job = &ctx.accounts.job;
+    let executor = job.executor.ok_or(VaultError::InvalidJobStatus)?;
+    
+    // Return the unused escrow now; only the charged fee stays disputed
+    let refunded_amount = job.refund_amount();
+    fees::transfer_tokens(
+        &token_program,
+        &escrow,
+        &ctx.accounts.caller_token_account.to_account_info(),
+        &escrow,
+        refunded_amount,
+        escrow_seeds,
+    )?;
+    
+    // Post the caller's bond into escrow
+    let bond_amount = ctx.accounts.vault_state.dispute_config.dispute_bond;
+    fees::transfer_tokens(
+        &token_program,
+        &ctx.accounts.caller_token_account.to_account_info(),
+        &escrow,
+        &ctx.accounts.caller.to_account_info(),
+        bond_amount,
+        &[],
+    )?;
+    
+    // The executor's open settlement carries over to the record
+    let execution_record = &mut ctx.accounts.execution_record;
+    execution_record.set_inner(ExecutionRecord::from_job(job, executor, Clock::get()?.unix_timestamp));
+    
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.open(
+        execution_record.key(),
+        ctx.accounts.caller.key(),
+        bond_amount,
+        evidence_uri,
+        ctx.accounts.vault_state.dispute_config.dispute_timeout,
+    )?;
+    
+    // Update prompt statistics; revenue is counted if the dispute is rejected
+    ctx.accounts.prompt_data.record_execution(
+        job.execution_time_ms,
+        job.success,
+        job.failure_code,
+    );
+    
+    // Update windowed analytics
+    let prompt_analytics = &mut ctx.accounts.prompt_analytics;
+    if !prompt_analytics.is_initialized() {
+        prompt_analytics.initialize(job.prompt_id.clone());
+    }
+    prompt_analytics.record_execution(job.success, job.execution_time_ms);
+    
+    emit!(JobDisputed {
+        job: job.key(),
+        execution_record: execution_record.key(),
+        caller: job.caller,
+        executor,
+        refunded_amount,
+        timestamp: dispute.opened_at,
+    });
+    
+    emit!(DisputeOpened {
+        dispute: dispute.key(),
+        execution_record: dispute.execution_record,
+        challenger: dispute.challenger,
+        bond_amount,
+        evidence_uri: dispute.evidence_uri.clone(),
+        resolve_deadline: dispute.resolve_deadline,
+        timestamp: dispute.opened_at,
+    });
+    
+    msg!("Job {} disputed by caller, moved to execution record: {}", job.job_id, execution_record.key());
+    msg!("Disputed fee: {}, refunded: {}, bond: {}",
+         execution_record.fee_breakdown.total_fee, refunded_amount, bond_amount);
+    
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::VaultPaused;
 
 #[derive(Accounts)]
 pub struct EmergencyPause<'info> {
@@ -19,9 +20,18 @@ pub struct EmergencyPause<'info> {
 pub fn handler(ctx: Context<EmergencyPause>) -> Result<()> {
     let vault_state = &mut ctx.accounts.vault_state;
     
+    // Re-pausing must not push back the emergency withdraw grace window
+    if !vault_state.is_paused {
+        vault_state.paused_at = Clock::get()?.unix_timestamp;
+    }
     vault_state.is_paused = true;
     vault_state.touch();
     
+    emit!(VaultPaused {
+        admin: ctx.accounts.admin.key(),
+        paused_at: vault_state.paused_at,
+    });
+    
     msg!("Emergency pause activated by admin: {}", ctx.accounts.admin.key());
     
     Ok(())
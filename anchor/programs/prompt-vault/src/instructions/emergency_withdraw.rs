@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::EmergencyWithdrawn;
+
+#[derive(Accounts)]
+#[instruction(position_index: u16)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"stake",
+            prompt_data.id.as_bytes(),
+            staker.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump,
+        constraint = stake_account.owner == staker.key() @ VaultError::UnauthorizedAuthor
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    /// CHECK: Slash proposal for the prompt - validated by seeds, read only while a slash is pending
+    #[account(
+        seeds = [b"slash", prompt_data.id.as_bytes()],
+        bump
+    )]
+    pub slash_proposal: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_emergency_withdraw_open(Clock::get()?.unix_timestamp) @ VaultError::EmergencyWithdrawUnavailable
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = staker_token_account.owner == staker.key()
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Stake pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump
+    )]
+    pub stake_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<EmergencyWithdraw>, _position_index: u16) -> Result<()> {
+    // A pending slash only holds stake until its dispute window and the grace period pass
+    if ctx.accounts.prompt_data.slash_pending {
+        let slash_proposal_data = ctx.accounts.slash_proposal.try_borrow_data()?;
+        let slash_proposal = SlashProposal::try_deserialize(&mut &slash_proposal_data[..])?;
+        require!(
+            !slash_proposal.locks_emergency_withdraw(
+                ctx.accounts.vault_state.staking_config.emergency_withdraw_grace,
+                Clock::get()?.unix_timestamp,
+            ),
+            VaultError::SlashPending
+        );
+    }
+    
+    let stake_account = &mut ctx.accounts.stake_account;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    
+    // Principal is returned net of executed slashing; lockups and cooldowns do not apply
+    stake_account.apply_slashing(prompt_data.slash_factor);
+    let amount = stake_account.staked_amount;
+    require!(amount > 0, VaultError::InsufficientStake);
+    
+    // Pending rewards are forfeited
+    let forfeited_rewards = stake_account.pending_rewards;
+    stake_account.remove_stake(amount)?;
+    stake_account.pending_rewards = 0;
+    stake_account.last_claim = Clock::get()?.unix_timestamp;
+    prompt_data.total_staked = prompt_data.total_staked.saturating_sub(amount);
+    
+    // Return principal from the stake pool
+    let stake_pool_bump = [ctx.bumps.stake_pool];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stake_pool", &stake_pool_bump]];
+    let transfer_to_staker = Transfer {
+        from: ctx.accounts.stake_pool.to_account_info(),
+        to: ctx.accounts.staker_token_account.to_account_info(),
+        authority: ctx.accounts.stake_pool.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_to_staker,
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    emit!(EmergencyWithdrawn {
+        stake_account: stake_account.key(),
+        prompt_id: stake_account.prompt_id.clone(),
+        staker: stake_account.owner,
+        position_index: stake_account.position_index,
+        amount,
+        forfeited_rewards,
+        timestamp: stake_account.last_claim,
+    });
+    
+    msg!("Emergency withdraw of {} tokens from prompt: {}", amount, stake_account.prompt_id);
+    msg!("Forfeited settled rewards: {}", forfeited_rewards);
+    
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::SlashExecuted;
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"slash", prompt_data.id.as_bytes()],
+        bump,
+        constraint = slash_proposal.is_executable() @ VaultError::DisputeWindowNotElapsed,
+        close = proposer
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    /// CHECK: Receives the proposal rent - validated against the proposal
+    #[account(
+        mut,
+        constraint = proposer.key() == slash_proposal.proposer @ VaultError::InvalidAccountData
+    )]
+    pub proposer: AccountInfo<'info>,
+    
+    /// CHECK: Stake pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"stake_pool"],
+        bump
+    )]
+    pub stake_pool: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == vault_state.treasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// Mint of the staked token, burned from for burn slashes
+    #[account(
+        mut,
+        address = vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub stake_mint: Account<'info, Mint>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ExecuteSlash>) -> Result<()> {
+    let slash_proposal = &ctx.accounts.slash_proposal;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    
+    // Reduce the prompt's stake; positions pick up the new factor lazily
+    let slashed_amount = prompt_data.apply_slash(slash_proposal.slash_bps);
+    prompt_data.touch();
+    
+    if slashed_amount > 0 {
+        let stake_pool_bump = [ctx.bumps.stake_pool];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"stake_pool", &stake_pool_bump]];
+        
+        match slash_proposal.destination {
+            SlashDestination::Treasury => {
+                let transfer_to_treasury = Transfer {
+                    from: ctx.accounts.stake_pool.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.stake_pool.to_account_info(),
+                };
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        transfer_to_treasury,
+                        signer_seeds,
+                    ),
+                    slashed_amount,
+                )?;
+            }
+            SlashDestination::Burn => {
+                let burn_from_pool = Burn {
+                    mint: ctx.accounts.stake_mint.to_account_info(),
+                    from: ctx.accounts.stake_pool.to_account_info(),
+                    authority: ctx.accounts.stake_pool.to_account_info(),
+                };
+                token::burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        burn_from_pool,
+                        signer_seeds,
+                    ),
+                    slashed_amount,
+                )?;
+            }
+        }
+    }
+    
+    emit!(SlashExecuted {
+        prompt_id: prompt_data.id.clone(),
+        slashed_amount,
+        destination: slash_proposal.destination,
+        remaining_stake: prompt_data.total_staked,
+        slash_factor: prompt_data.slash_factor,
+        timestamp: prompt_data.last_updated,
+    });
+    
+    msg!("Slash executed for prompt: {}", prompt_data.id);
+    msg!("Slashed: {} tokens, remaining stake: {}", slashed_amount, prompt_data.total_staked);
+    
+    Ok(())
+}
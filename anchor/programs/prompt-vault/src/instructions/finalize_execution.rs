@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{self, FeeRecipients};
+use crate::events::ExecutionFinalized;
+
+#[derive(Accounts)]
+pub struct FinalizeExecution<'info> {
+    #[account(
+        mut,
+        constraint = execution_record.is_finalizable(Clock::get()?.unix_timestamp) @ VaultError::ChallengeWindowNotElapsed
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", execution_record.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        mut,
+        seeds = [b"analytics", execution_record.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_analytics: Box<Account<'info, PromptAnalytics>>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = author_token_account.owner == prompt_data.author
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == vault_state.treasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Validator pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"validator_pool"],
+        bump
+    )]
+    pub validator_pool: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        address = vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub payment_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", execution_record.executor.as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    /// Integrator the execution was routed through; required if the record has one
+    #[account(
+        mut,
+        seeds = [b"integrator", integrator.authority.as_ref()],
+        bump,
+        constraint = execution_record.integrator == Some(integrator.authority) @ VaultError::InvalidIntegrator
+    )]
+    pub integrator: Option<Account<'info, IntegratorAccount>>,
+    
+    #[account(
+        mut,
+        constraint = execution_record.integrator == Some(integrator_token_account.owner) @ VaultError::InvalidIntegrator,
+        constraint = integrator_token_account.mint == vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub integrator_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<FinalizeExecution>) -> Result<()> {
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    
+    // Release the escrowed fee now that the challenge window has passed
+    let integrator_token_account = ctx.accounts.integrator_token_account
+        .as_ref()
+        .map(|account| account.to_account_info());
+    fees::distribute_fee(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.execution_escrow,
+        &ctx.accounts.execution_escrow,
+        &FeeRecipients {
+            author_token_account: &ctx.accounts.author_token_account.to_account_info(),
+            treasury_token_account: &ctx.accounts.treasury_token_account.to_account_info(),
+            validator_pool: &ctx.accounts.validator_pool,
+            payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+            integrator_token_account: integrator_token_account.as_ref(),
+        },
+        &ctx.accounts.execution_record.fee_breakdown,
+        signer_seeds,
+    )?;
+    fees::credit_integrator(
+        ctx.accounts.integrator.as_deref_mut(),
+        ctx.accounts.execution_record.fee_breakdown.integrator_amount,
+    )?;
+    
+    // Remaining accounts: attesting validators' executor accounts
+    let execution_record = &mut ctx.accounts.execution_record;
+    fees::settle_attesters(
+        &execution_record.attesters,
+        ctx.remaining_accounts,
+        execution_record.fee_breakdown.validator_amount,
+    )?;
+    
+    execution_record.status = ExecutionStatus::Finalized;
+    ctx.accounts.executor.close_settlement();
+    
+    if execution_record.success {
+        let revenue = execution_record.fee_breakdown.revenue();
+        ctx.accounts.prompt_data.record_revenue(revenue);
+        ctx.accounts.prompt_analytics.record_revenue(revenue);
+    }
+    
+    emit!(ExecutionFinalized {
+        execution_record: execution_record.key(),
+        prompt_id: execution_record.prompt_id.clone(),
+        fee_breakdown: execution_record.fee_breakdown.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Execution finalized for prompt: {}", execution_record.prompt_id);
+    msg!("Released fee: {}", execution_record.fee_breakdown.total_fee);
+    
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use crate::errors::*;
+use super::accept_job::{settle, SettleJob};
+
+pub fn handler(ctx: Context<SettleJob>) -> Result<()> {
+    // Anyone may finalize once the caller's acceptance window has passed
+    require!(
+        ctx.accounts.job.is_finalizable(Clock::get()?.unix_timestamp),
+        VaultError::AcceptanceWindowNotElapsed
+    );
+    
+    settle(ctx, false)
+}
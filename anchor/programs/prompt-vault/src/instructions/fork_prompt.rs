@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::utils;
+use crate::events::PromptForked;
 
 #[derive(Accounts)]
 #[instruction(params: ForkPromptParams)]
@@ -46,10 +48,15 @@ pub fn handler(ctx: Context<ForkPrompt>, params: ForkPromptParams) -> Result<()>
     let clock = Clock::get()?;
     
     // Check if forking is allowed for this license type
-    match original_prompt.license_type {
-        LicenseType::Private => return Err(VaultError::ForkNotAllowed.into()),
-        _ => {} // Other license types allow forking
-    }
+    require!(
+        original_prompt.license_type != LicenseType::Private,
+        VaultError::ForkNotAllowed
+    );
+    
+    require!(
+        utils::validate_access_control(&params.access_control),
+        VaultError::InvalidAccessControl
+    );
     
     // Create initial version entry for the fork
     let initial_version = VersionEntry {
@@ -77,10 +84,27 @@ pub fn handler(ctx: Context<ForkPrompt>, params: ForkPromptParams) -> Result<()>
     new_prompt.royalty_config = RoyaltyConfig::default();
     new_prompt.tags = params.tags;
     new_prompt.access_control = params.access_control;
+    new_prompt.total_staked = 0;
+    new_prompt.slash_factor = SLASH_FACTOR_PRECISION;
+    new_prompt.slash_pending = false;
+    new_prompt.suspended_for_cause = false;
+    new_prompt.validator_quorum = 0;
+    new_prompt.refund_policy = RefundPolicy::None;
     
     // Update vault state
     vault_state.increment_prompt_count();
     
+    emit!(PromptForked {
+        original_prompt: original_prompt.key(),
+        original_prompt_id: original_prompt.id.clone(),
+        new_prompt: new_prompt.key(),
+        new_prompt_id: new_prompt.id.clone(),
+        forker: new_prompt.author,
+        license_type: new_prompt.license_type,
+        fee_amount: new_prompt.fee_amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
     msg!("Prompt forked: {} -> {}", params.original_prompt_id, params.new_prompt_id);
     msg!("Forker: {}", ctx.accounts.forker.key());
     
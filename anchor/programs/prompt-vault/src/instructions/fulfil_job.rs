@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::state::*;
+use crate::errors::*;
+use crate::utils;
+use crate::events::JobFulfilled;
+
+#[derive(Accounts)]
+pub struct FulfilJob<'info> {
+    #[account(
+        mut,
+        constraint = job.status == JobStatus::Claimed @ VaultError::InvalidJobStatus,
+        constraint = !job.is_expired(Clock::get()?.unix_timestamp) @ VaultError::JobExpired,
+        constraint = job.executor == Some(authority.key()) @ VaultError::UnauthorizedExecutor
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = executor.can_execute() @ VaultError::ExecutorNotActive
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    pub authority: Signer<'info>,
+    
+    /// CHECK: Instructions sysvar used to inspect the Ed25519 attestation
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+pub fn handler(ctx: Context<FulfilJob>, params: FulfilJobParams) -> Result<()> {
+    // Validate parameters
+    params.validate()?;
+    
+    let job_key = ctx.accounts.job.key();
+    let job = &mut ctx.accounts.job;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    
+    // A quorum set after the job opened leaves it to expire and be refunded
+    require!(prompt_data.validator_quorum == 0, VaultError::ValidatorQuorumUnsupported);
+    
+    // Verify the executor's Ed25519 attestation carried in this transaction
+    let executor = &mut ctx.accounts.executor;
+    require!(
+        executor.has_signing_key(&params.signing_key),
+        VaultError::UnauthorizedExecutor
+    );
+    let signing_message = params.signing_message(&job_key, &job.input_hash);
+    let attested = utils::load_ed25519_signatures(&ctx.accounts.instructions)?
+        .iter()
+        .any(|entry| {
+            entry.pubkey == params.signing_key &&
+                entry.signature == params.signature &&
+                entry.message == signing_message
+        });
+    require!(attested, VaultError::InvalidExecutionSignature);
+    executor.executions_attested = executor.executions_attested.saturating_add(1);
+    
+    // Fix the charged fee now; the rest of the escrow goes back on settlement
+    let now = Clock::get()?.unix_timestamp;
+    prompt_data.apply_due_price_change(now);
+    let execution_fee = prompt_data
+        .execution_fee(params.input_tokens, params.output_tokens)
+        .min(job.escrowed_amount);
+    let refunded_amount = if params.success {
+        0
+    } else {
+        prompt_data.refund_policy.refund_amount(execution_fee)
+    };
+    let mut fee_breakdown = prompt_data.fee_breakdown(execution_fee - refunded_amount);
+    if params.failure_code == Some(FailureCode::ExecutorFault) {
+        // The creator is not paid for failures the executor caused
+        fee_breakdown.waive_creator_share();
+    }
+    
+    job.output_hash = params.output_hash;
+    job.signature = params.signature;
+    job.execution_time_ms = params.execution_time_ms;
+    job.input_tokens = params.input_tokens;
+    job.output_tokens = params.output_tokens;
+    job.success = params.success;
+    job.failure_code = params.failure_code;
+    job.refunded_amount = refunded_amount;
+    job.fee_breakdown = fee_breakdown;
+    job.status = JobStatus::Fulfilled;
+    // The caller gets at least the vault's challenge window to accept or dispute
+    let vault_state = &ctx.accounts.vault_state;
+    let acceptance_window = vault_state.job_config.acceptance_window
+        .max(vault_state.dispute_config.challenge_window);
+    job.acceptance_deadline = now.saturating_add(acceptance_window);
+    
+    emit!(JobFulfilled {
+        job: job_key,
+        executor: executor.authority,
+        output_hash: job.output_hash,
+        execution_time_ms: job.execution_time_ms,
+        input_tokens: job.input_tokens,
+        output_tokens: job.output_tokens,
+        success: job.success,
+        failure_code: job.failure_code,
+        fee_breakdown: job.fee_breakdown.clone(),
+        acceptance_deadline: job.acceptance_deadline,
+        timestamp: now,
+    });
+    
+    msg!("Job {} fulfilled by executor: {}", job.job_id, executor.authority);
+    msg!("Success: {}, acceptance deadline: {}", job.success, job.acceptance_deadline);
+    
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
-use crate::errors::*;
+use crate::events::VaultInitialized;
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -30,13 +30,35 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     // Initialize vault state
     vault_state.admin = ctx.accounts.admin.key();
     vault_state.treasury = params.treasury;
+    vault_state.payment_mint = params.payment_mint;
     vault_state.prompt_count = 0;
     vault_state.protocol_fee_bps = params.protocol_fee_bps;
     vault_state.creator_share_bps = params.creator_share_bps;
     vault_state.validator_share_bps = params.validator_share_bps;
     vault_state.is_paused = false;
+    vault_state.paused_at = 0;
     vault_state.created_at = clock.unix_timestamp;
     vault_state.last_updated = clock.unix_timestamp;
+    vault_state.staking_config = params.staking_config;
+    vault_state.slashing_config = params.slashing_config;
+    vault_state.execution_retention_period = params.execution_retention_period;
+    vault_state.registry_config = params.registry_config;
+    vault_state.dispute_config = params.dispute_config;
+    vault_state.job_config = params.job_config;
+    vault_state.price_change_notice_period = params.price_change_notice_period;
+    vault_state.integrator_config = params.integrator_config;
+    vault_state.discount_config = params.discount_config;
+    vault_state.active_validators = 0;
+    
+    emit!(VaultInitialized {
+        admin: vault_state.admin,
+        treasury: vault_state.treasury,
+        payment_mint: vault_state.payment_mint,
+        protocol_fee_bps: vault_state.protocol_fee_bps,
+        creator_share_bps: vault_state.creator_share_bps,
+        validator_share_bps: vault_state.validator_share_bps,
+        timestamp: clock.unix_timestamp,
+    });
     
     msg!("PromptVault initialized with admin: {}", ctx.accounts.admin.key());
     msg!("Treasury: {}", params.treasury);
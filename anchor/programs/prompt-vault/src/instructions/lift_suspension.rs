@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::PromptStatusChanged;
+
+#[derive(Accounts)]
+pub struct LiftSuspension<'info> {
+    #[account(
+        mut,
+        seeds = [b"prompt", prompt_data.id.as_bytes()],
+        bump,
+        constraint = prompt_data.suspended_for_cause @ VaultError::InvalidStatusTransition,
+        constraint = !prompt_data.slash_pending @ VaultError::SlashPending
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_slashing_authority(&authority.key()) @ VaultError::UnauthorizedSlashingAuthority
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LiftSuspension>) -> Result<()> {
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    
+    let previous_status = prompt_data.status;
+    prompt_data.status = PromptStatus::Active;
+    prompt_data.suspended_for_cause = false;
+    prompt_data.touch();
+    
+    emit!(PromptStatusChanged {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        previous_status,
+        new_status: prompt_data.status,
+        timestamp: prompt_data.last_updated,
+    });
+    
+    msg!("Suspension lifted for prompt: {}", prompt_data.id);
+    msg!("Lifted by: {}", ctx.accounts.authority.key());
+    
+    Ok(())
+}
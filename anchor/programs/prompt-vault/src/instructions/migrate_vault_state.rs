@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::VaultMigrated;
+
+#[derive(Accounts)]
+pub struct MigrateVaultState<'info> {
+    /// CHECK: Legacy vault state - validated by seeds, owner and discriminator, and deserialized by hand
+    /// since it does not fit the current layout
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump,
+        owner = crate::ID
+    )]
+    pub vault_state: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateVaultState>, params: MigrateVaultStateParams) -> Result<()> {
+    params.validate()?;
+    
+    let vault_info = ctx.accounts.vault_state.to_account_info();
+    let previous_size = vault_info.data_len();
+    require!(previous_size < VaultState::SPACE, VaultError::VaultAlreadyMigrated);
+    
+    let legacy = {
+        let data = vault_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == VaultState::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyVaultState::deserialize(&mut &data[8..])?
+    };
+    require!(legacy.admin == ctx.accounts.admin.key(), VaultError::UnauthorizedAdmin);
+    
+    // The admin tops the account up to stay rent exempt at its new size
+    let rent_due = Rent::get()?
+        .minimum_balance(VaultState::SPACE)
+        .saturating_sub(vault_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: vault_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    vault_info.realloc(VaultState::SPACE, true)?;
+    
+    let clock = Clock::get()?;
+    let vault_state = VaultState::from_legacy(legacy, params, clock.unix_timestamp);
+    {
+        let mut data = vault_info.try_borrow_mut_data()?;
+        vault_state.try_serialize(&mut &mut data[..])?;
+    }
+    
+    emit!(VaultMigrated {
+        admin: vault_state.admin,
+        payment_mint: vault_state.payment_mint,
+        previous_size: previous_size as u64,
+        new_size: VaultState::SPACE as u64,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Vault state migrated from {} to {} bytes", previous_size, VaultState::SPACE);
+    
+    Ok(())
+}
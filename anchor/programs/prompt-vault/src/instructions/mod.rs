@@ -1,5 +1,3 @@
-use anchor_lang::prelude::*;
-
 pub mod initialize;
 pub mod register_prompt;
 pub mod record_execution;
@@ -10,22 +8,198 @@ pub mod update_status;
 pub mod transfer_ownership;
 pub mod fork_prompt;
 pub mod stake_for_ranking;
+pub mod add_stake;
 pub mod unstake;
 pub mod claim_rewards;
 pub mod emergency_pause;
 pub mod resume_operations;
+pub mod update_vault_config;
+pub mod migrate_vault_state;
+pub mod propose_slash;
+pub mod cancel_slash;
+pub mod execute_slash;
+pub mod lift_suspension;
+pub mod emergency_withdraw;
+pub mod close_stake_account;
+pub mod close_execution_record;
+pub mod register_executor;
+pub mod update_executor;
+pub mod set_executor_status;
+pub mod begin_executor_exit;
+pub mod withdraw_executor_bond;
+pub mod finalize_execution;
+pub mod open_dispute;
+pub mod vote_on_dispute;
+pub mod resolve_dispute;
+pub mod attest_execution;
+pub mod refund_unattested_execution;
+pub mod claim_validator_rewards;
+pub mod quote_execution;
+pub mod check_access;
+pub mod open_job;
+pub mod claim_job;
+pub mod fulfil_job;
+pub mod accept_job;
+pub mod finalize_job;
+pub mod dispute_job;
+pub mod refund_expired_job;
+pub mod register_integrator;
+pub mod update_integrator;
+pub mod sponsor_prompt;
+pub mod withdraw_sponsorship;
+
+
+// Every instruction module exports a `handler`, which lib.rs calls by path, so
+// only the accounts structs and the client modules Anchor generates for them
+// are re-exported here
+pub use initialize::Initialize;
+pub use register_prompt::RegisterPrompt;
+pub use record_execution::RecordExecution;
+pub use create_version::CreateVersion;
+pub use update_metadata::UpdateMetadata;
+pub use update_license::UpdateLicense;
+pub use update_status::UpdateStatus;
+pub use transfer_ownership::TransferOwnership;
+pub use fork_prompt::ForkPrompt;
+pub use stake_for_ranking::StakeForRanking;
+pub use add_stake::AddStake;
+pub use unstake::Unstake;
+pub use claim_rewards::ClaimRewards;
+pub use emergency_pause::EmergencyPause;
+pub use resume_operations::ResumeOperations;
+pub use update_vault_config::UpdateVaultConfig;
+pub use migrate_vault_state::MigrateVaultState;
+pub use propose_slash::ProposeSlash;
+pub use cancel_slash::CancelSlash;
+pub use execute_slash::ExecuteSlash;
+pub use lift_suspension::LiftSuspension;
+pub use emergency_withdraw::EmergencyWithdraw;
+pub use close_stake_account::CloseStakeAccount;
+pub use close_execution_record::CloseExecutionRecord;
+pub use register_executor::RegisterExecutor;
+pub use update_executor::UpdateExecutor;
+pub use set_executor_status::SetExecutorStatus;
+pub use begin_executor_exit::BeginExecutorExit;
+pub use withdraw_executor_bond::WithdrawExecutorBond;
+pub use finalize_execution::FinalizeExecution;
+pub use open_dispute::OpenDispute;
+pub use vote_on_dispute::VoteOnDispute;
+pub use resolve_dispute::ResolveDispute;
+pub use attest_execution::AttestExecution;
+pub use refund_unattested_execution::RefundUnattestedExecution;
+pub use claim_validator_rewards::ClaimValidatorRewards;
+pub use quote_execution::QuoteExecution;
+pub use check_access::CheckAccess;
+pub use open_job::OpenJob;
+pub use claim_job::ClaimJob;
+pub use fulfil_job::FulfilJob;
+pub use accept_job::SettleJob;
+pub use dispute_job::DisputeJob;
+pub use refund_expired_job::RefundExpiredJob;
+pub use register_integrator::RegisterIntegrator;
+pub use update_integrator::UpdateIntegrator;
+pub use sponsor_prompt::SponsorPrompt;
+pub use withdraw_sponsorship::WithdrawSponsorship;
+
+pub(crate) use self::{
+    initialize::__client_accounts_initialize,
+    register_prompt::__client_accounts_register_prompt,
+    record_execution::__client_accounts_record_execution,
+    create_version::__client_accounts_create_version,
+    update_metadata::__client_accounts_update_metadata,
+    update_license::__client_accounts_update_license,
+    update_status::__client_accounts_update_status,
+    transfer_ownership::__client_accounts_transfer_ownership,
+    fork_prompt::__client_accounts_fork_prompt,
+    stake_for_ranking::__client_accounts_stake_for_ranking,
+    add_stake::__client_accounts_add_stake,
+    unstake::__client_accounts_unstake,
+    claim_rewards::__client_accounts_claim_rewards,
+    emergency_pause::__client_accounts_emergency_pause,
+    resume_operations::__client_accounts_resume_operations,
+    update_vault_config::__client_accounts_update_vault_config,
+    migrate_vault_state::__client_accounts_migrate_vault_state,
+    propose_slash::__client_accounts_propose_slash,
+    cancel_slash::__client_accounts_cancel_slash,
+    execute_slash::__client_accounts_execute_slash,
+    lift_suspension::__client_accounts_lift_suspension,
+    emergency_withdraw::__client_accounts_emergency_withdraw,
+    close_stake_account::__client_accounts_close_stake_account,
+    close_execution_record::__client_accounts_close_execution_record,
+    register_executor::__client_accounts_register_executor,
+    update_executor::__client_accounts_update_executor,
+    set_executor_status::__client_accounts_set_executor_status,
+    begin_executor_exit::__client_accounts_begin_executor_exit,
+    withdraw_executor_bond::__client_accounts_withdraw_executor_bond,
+    finalize_execution::__client_accounts_finalize_execution,
+    open_dispute::__client_accounts_open_dispute,
+    vote_on_dispute::__client_accounts_vote_on_dispute,
+    resolve_dispute::__client_accounts_resolve_dispute,
+    attest_execution::__client_accounts_attest_execution,
+    refund_unattested_execution::__client_accounts_refund_unattested_execution,
+    claim_validator_rewards::__client_accounts_claim_validator_rewards,
+    quote_execution::__client_accounts_quote_execution,
+    check_access::__client_accounts_check_access,
+    open_job::__client_accounts_open_job,
+    claim_job::__client_accounts_claim_job,
+    fulfil_job::__client_accounts_fulfil_job,
+    accept_job::__client_accounts_settle_job,
+    dispute_job::__client_accounts_dispute_job,
+    refund_expired_job::__client_accounts_refund_expired_job,
+    register_integrator::__client_accounts_register_integrator,
+    update_integrator::__client_accounts_update_integrator,
+    sponsor_prompt::__client_accounts_sponsor_prompt,
+    withdraw_sponsorship::__client_accounts_withdraw_sponsorship,
+};
 
-pub use initialize::*;
-pub use register_prompt::*;
-pub use record_execution::*;
-pub use create_version::*;
-pub use update_metadata::*;
-pub use update_license::*;
-pub use update_status::*;
-pub use transfer_ownership::*;
-pub use fork_prompt::*;
-pub use stake_for_ranking::*;
-pub use unstake::*;
-pub use claim_rewards::*;
-pub use emergency_pause::*;
-pub use resume_operations::*; 
\ No newline at end of file
+#[cfg(feature = "cpi")]
+pub(crate) use self::{
+    initialize::__cpi_client_accounts_initialize,
+    register_prompt::__cpi_client_accounts_register_prompt,
+    record_execution::__cpi_client_accounts_record_execution,
+    create_version::__cpi_client_accounts_create_version,
+    update_metadata::__cpi_client_accounts_update_metadata,
+    update_license::__cpi_client_accounts_update_license,
+    update_status::__cpi_client_accounts_update_status,
+    transfer_ownership::__cpi_client_accounts_transfer_ownership,
+    fork_prompt::__cpi_client_accounts_fork_prompt,
+    stake_for_ranking::__cpi_client_accounts_stake_for_ranking,
+    add_stake::__cpi_client_accounts_add_stake,
+    unstake::__cpi_client_accounts_unstake,
+    claim_rewards::__cpi_client_accounts_claim_rewards,
+    emergency_pause::__cpi_client_accounts_emergency_pause,
+    resume_operations::__cpi_client_accounts_resume_operations,
+    update_vault_config::__cpi_client_accounts_update_vault_config,
+    migrate_vault_state::__cpi_client_accounts_migrate_vault_state,
+    propose_slash::__cpi_client_accounts_propose_slash,
+    cancel_slash::__cpi_client_accounts_cancel_slash,
+    execute_slash::__cpi_client_accounts_execute_slash,
+    lift_suspension::__cpi_client_accounts_lift_suspension,
+    emergency_withdraw::__cpi_client_accounts_emergency_withdraw,
+    close_stake_account::__cpi_client_accounts_close_stake_account,
+    close_execution_record::__cpi_client_accounts_close_execution_record,
+    register_executor::__cpi_client_accounts_register_executor,
+    update_executor::__cpi_client_accounts_update_executor,
+    set_executor_status::__cpi_client_accounts_set_executor_status,
+    begin_executor_exit::__cpi_client_accounts_begin_executor_exit,
+    withdraw_executor_bond::__cpi_client_accounts_withdraw_executor_bond,
+    finalize_execution::__cpi_client_accounts_finalize_execution,
+    open_dispute::__cpi_client_accounts_open_dispute,
+    vote_on_dispute::__cpi_client_accounts_vote_on_dispute,
+    resolve_dispute::__cpi_client_accounts_resolve_dispute,
+    attest_execution::__cpi_client_accounts_attest_execution,
+    refund_unattested_execution::__cpi_client_accounts_refund_unattested_execution,
+    claim_validator_rewards::__cpi_client_accounts_claim_validator_rewards,
+    quote_execution::__cpi_client_accounts_quote_execution,
+    check_access::__cpi_client_accounts_check_access,
+    open_job::__cpi_client_accounts_open_job,
+    claim_job::__cpi_client_accounts_claim_job,
+    fulfil_job::__cpi_client_accounts_fulfil_job,
+    accept_job::__cpi_client_accounts_settle_job,
+    dispute_job::__cpi_client_accounts_dispute_job,
+    refund_expired_job::__cpi_client_accounts_refund_expired_job,
+    register_integrator::__cpi_client_accounts_register_integrator,
+    update_integrator::__cpi_client_accounts_update_integrator,
+    sponsor_prompt::__cpi_client_accounts_sponsor_prompt,
+    withdraw_sponsorship::__cpi_client_accounts_withdraw_sponsorship,
+};
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::DisputeOpened;
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        constraint = execution_record.is_disputable(Clock::get()?.unix_timestamp) @ VaultError::ExecutionNotDisputable
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        init,
+        payer = challenger,
+        space = Dispute::SPACE,
+        seeds = [b"dispute", execution_record.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = challenger_token_account.owner == challenger.key()
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenDispute>, evidence_uri: String) -> Result<()> {
+    let bond_amount = ctx.accounts.vault_state.dispute_config.dispute_bond;
+    
+    // Post the challenger's bond into escrow
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.challenger_token_account.to_account_info(),
+        &ctx.accounts.execution_escrow,
+        &ctx.accounts.challenger.to_account_info(),
+        bond_amount,
+        &[],
+    )?;
+    
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.open(
+        ctx.accounts.execution_record.key(),
+        ctx.accounts.challenger.key(),
+        bond_amount,
+        evidence_uri,
+        ctx.accounts.vault_state.dispute_config.dispute_timeout,
+    )?;
+    
+    emit!(DisputeOpened {
+        dispute: dispute.key(),
+        execution_record: dispute.execution_record,
+        challenger: dispute.challenger,
+        bond_amount,
+        evidence_uri: dispute.evidence_uri.clone(),
+        resolve_deadline: dispute.resolve_deadline,
+        timestamp: dispute.opened_at,
+    });
+    
+    let execution_record = &mut ctx.accounts.execution_record;
+    execution_record.status = ExecutionStatus::Disputed;
+    
+    msg!("Dispute opened on execution for prompt: {}", execution_record.prompt_id);
+    msg!("Challenger: {}, bond: {}", ctx.accounts.challenger.key(), bond_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::JobOpened;
+
+#[derive(Accounts)]
+#[instruction(params: OpenJobParams)]
+pub struct OpenJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"prompt", params.prompt_id.as_bytes()],
+        bump,
+        constraint = prompt_data.is_accessible() @ VaultError::PromptNotAccessible
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        init,
+        payer = caller,
+        space = ExecutionJob::SPACE,
+        seeds = [
+            b"job",
+            params.prompt_id.as_bytes(),
+            caller.key().as_ref(),
+            params.job_id.as_bytes()
+        ],
+        bump
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = UserUsage::SPACE,
+        seeds = [b"usage", params.prompt_id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub user_usage: Account<'info, UserUsage>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == caller.key(),
+        constraint = caller_token_account.mint == vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<OpenJob>, params: OpenJobParams) -> Result<()> {
+    // Validate parameters
+    params.validate()?;
+    
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    let clock = Clock::get()?;
+    
+    // Jobs settle on the caller's acceptance, which cannot wait on validator attestations
+    require!(prompt_data.validator_quorum == 0, VaultError::ValidatorQuorumUnsupported);
+    
+    // Check access permissions; usage is counted when the job is opened
+    let caller_token_balance = ctx.accounts.caller_token_account.amount;
+    require!(
+        prompt_data.has_access(&ctx.accounts.caller.key(), caller_token_balance),
+        VaultError::InsufficientTokenBalance
+    );
+    let user_usage = &mut ctx.accounts.user_usage;
+    user_usage.user = ctx.accounts.caller.key();
+    user_usage.prompt_id = params.prompt_id.clone();
+    user_usage.record_execution(prompt_data.access_control.daily_usage_limit)?;
+    prompt_data.admit_execution(clock.slot)?;
+    
+    // Escrow the most the job can cost; the unused part and any refund are
+    // returned once the job is settled
+    prompt_data.apply_due_price_change(clock.unix_timestamp);
+    let escrowed_amount = prompt_data.max_execution_fee()
+        .ok_or(VaultError::UnboundedExecutionFee)?;
+    require!(escrowed_amount <= params.max_fee, VaultError::FeeExceedsMaximum);
+    require!(caller_token_balance >= escrowed_amount, VaultError::InsufficientPayment);
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.caller_token_account.to_account_info(),
+        &ctx.accounts.execution_escrow,
+        &ctx.accounts.caller.to_account_info(),
+        escrowed_amount,
+        &[],
+    )?;
+    
+    let job = &mut ctx.accounts.job;
+    job.prompt_id = params.prompt_id.clone();
+    job.job_id = params.job_id.clone();
+    job.caller = ctx.accounts.caller.key();
+    job.version = prompt_data.current_version.clone();
+    job.input_hash = params.input_hash;
+    job.escrowed_amount = escrowed_amount;
+    job.status = JobStatus::Open;
+    job.created_at = clock.unix_timestamp;
+    job.expires_at = clock.unix_timestamp.saturating_add(ctx.accounts.vault_state.job_config.job_timeout);
+    
+    emit!(JobOpened {
+        job: job.key(),
+        prompt_id: job.prompt_id.clone(),
+        job_id: job.job_id.clone(),
+        caller: job.caller,
+        input_hash: job.input_hash,
+        escrowed_amount,
+        expires_at: job.expires_at,
+        timestamp: job.created_at,
+    });
+    
+    msg!("Job {} opened for prompt: {}", params.job_id, params.prompt_id);
+    msg!("Escrowed: {}, expires at: {}", escrowed_amount, job.expires_at);
+    
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::SlashProposed;
+
+#[derive(Accounts)]
+#[instruction(params: ProposeSlashParams)]
+pub struct ProposeSlash<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SlashProposal::SPACE,
+        seeds = [b"slash", params.prompt_id.as_bytes()],
+        bump
+    )]
+    pub slash_proposal: Account<'info, SlashProposal>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", params.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_slashing_authority(&authority.key()) @ VaultError::UnauthorizedSlashingAuthority
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ProposeSlash>, params: ProposeSlashParams) -> Result<()> {
+    // Validate parameters
+    params.validate()?;
+    
+    let slash_proposal = &mut ctx.accounts.slash_proposal;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    let slashing_config = &ctx.accounts.vault_state.slashing_config;
+    let clock = Clock::get()?;
+    
+    // Proposing a slash suspends the prompt for cause
+    if prompt_data.status != PromptStatus::Removed {
+        prompt_data.status = PromptStatus::Suspended;
+        prompt_data.suspended_for_cause = true;
+    }
+    prompt_data.slash_pending = true;
+    prompt_data.touch();
+    
+    slash_proposal.prompt_id = params.prompt_id.clone();
+    slash_proposal.proposer = ctx.accounts.authority.key();
+    slash_proposal.slash_bps = slashing_config.slash_bps;
+    slash_proposal.destination = slashing_config.destination;
+    slash_proposal.reason = params.reason;
+    slash_proposal.proposed_at = clock.unix_timestamp;
+    slash_proposal.executable_at = clock.unix_timestamp.saturating_add(slashing_config.dispute_window);
+    
+    emit!(SlashProposed {
+        slash_proposal: slash_proposal.key(),
+        prompt_id: slash_proposal.prompt_id.clone(),
+        proposer: slash_proposal.proposer,
+        slash_bps: slash_proposal.slash_bps,
+        destination: slash_proposal.destination,
+        reason: slash_proposal.reason.clone(),
+        executable_at: slash_proposal.executable_at,
+    });
+    
+    msg!("Slash proposed for prompt: {}", params.prompt_id);
+    msg!("Slash: {}bps of {} staked, executable at: {}",
+         slash_proposal.slash_bps, prompt_data.total_staked, slash_proposal.executable_at);
+    
+    Ok(())
+}
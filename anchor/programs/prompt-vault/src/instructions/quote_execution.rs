@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(prompt_id: String)]
+pub struct QuoteExecution<'info> {
+    #[account(
+        seeds = [b"prompt", prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    /// CHECK: Caller the quote is for; does not need to sign
+    pub caller: UncheckedAccount<'info>,
+    
+    /// Caller's payment token account, needed to evaluate token-gated access
+    #[account(
+        constraint = caller_token_account.owner == caller.key()
+    )]
+    pub caller_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// One of the caller's stake positions on the prompt, needed to evaluate staked discount
+    /// tiers; tiers are met by a single position, so callers pass their largest
+    #[account(
+        seeds = [
+            b"stake",
+            prompt_id.as_bytes(),
+            caller.key().as_ref(),
+            &caller_stake.position_index.to_le_bytes()
+        ],
+        bump,
+        constraint = caller_stake.owner == caller.key(),
+        constraint = caller_stake.prompt_id == prompt_id
+    )]
+    pub caller_stake: Option<Account<'info, StakeAccount>>,
+    
+    /// CHECK: Caller's usage of the prompt - validated by seeds, empty if the caller has never executed it
+    #[account(
+        seeds = [b"usage", prompt_id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub user_usage: UncheckedAccount<'info>,
+}
+
+/// Read-only; intended to be simulated, with the quote read from return data
+pub fn handler(ctx: Context<QuoteExecution>, _prompt_id: String) -> Result<ExecutionQuote> {
+    // Quote against the price in effect now, without writing the account
+    let mut prompt_data = (*ctx.accounts.prompt_data).clone();
+    prompt_data.apply_due_price_change(Clock::get()?.unix_timestamp);
+    let vault_state = &ctx.accounts.vault_state;
+    
+    let caller_token_balance = ctx.accounts.caller_token_account
+        .as_ref()
+        .filter(|account| account.mint == vault_state.payment_mint)
+        .map_or(0, |account| account.amount);
+    let staked_amount = ctx.accounts.caller_stake
+        .as_ref()
+        .map_or(0, |stake| stake.effective_stake(prompt_data.slash_factor));
+    let discount_bps = vault_state.discount_config.discount_bps(staked_amount, caller_token_balance);
+    let base_fee = prompt_data.execution_fee(0, 0);
+    let base_fee = base_fee - crate::utils::calculate_bps_amount(base_fee, discount_bps);
+    
+    // Same decision `check_access` returns, covering quotas and rate limits
+    let user_usage = UserUsage::load_at(&ctx.accounts.user_usage)?;
+    let access_decision = prompt_data.access_decision(
+        &ctx.accounts.caller.key(),
+        caller_token_balance,
+        user_usage.as_ref(),
+    );
+    
+    let quote = ExecutionQuote {
+        prompt_id: prompt_data.id.clone(),
+        version: prompt_data.current_version.clone(),
+        fee_breakdown: prompt_data.fee_breakdown(base_fee),
+        discount_bps,
+        metered_pricing: prompt_data.metered_pricing,
+        pending_price_change: prompt_data.pending_price_change,
+        payment_mint: vault_state.payment_mint,
+        access_granted: access_decision.granted,
+        executable: prompt_data.is_accessible() && vault_state.is_operational(),
+    };
+    
+    msg!("Quote for prompt {}: {} total fee", quote.prompt_id, quote.fee_breakdown.total_fee);
+    
+    Ok(quote)
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::*;
+use crate::fees::{self, FeeRecipients};
+use crate::utils;
+use crate::events::ExecutionRecorded;
 
 #[derive(Accounts)]
 #[instruction(params: RecordExecutionParams)]
@@ -14,20 +18,49 @@ pub struct RecordExecution<'info> {
     )]
     pub prompt_data: Account<'info, PromptData>,
     
+    // init_if_needed so that a retried execution ID surfaces
+    // ExecutionRecordAlreadyExists instead of a system program error
     #[account(
-        init,
+        init_if_needed,
         payer = caller,
         space = ExecutionRecord::SPACE,
         seeds = [
             b"execution",
             params.prompt_id.as_bytes(),
             caller.key().as_ref(),
-            &Clock::get()?.unix_timestamp.to_le_bytes()
+            params.execution_id.as_bytes()
         ],
         bump
     )]
     pub execution_record: Account<'info, ExecutionRecord>,
     
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = ExecutionNonce::SPACE,
+        seeds = [b"execution_nonce", caller.key().as_ref()],
+        bump
+    )]
+    pub execution_nonce: Account<'info, ExecutionNonce>,
+    
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = UserUsage::SPACE,
+        seeds = [b"usage", params.prompt_id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub user_usage: Account<'info, UserUsage>,
+    
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = PromptAnalytics::SPACE,
+        seeds = [b"analytics", params.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_analytics: Box<Account<'info, PromptAnalytics>>,
+    
     #[account(
         seeds = [b"vault_state"],
         bump,
@@ -65,12 +98,90 @@ pub struct RecordExecution<'info> {
     )]
     pub validator_pool: AccountInfo<'info>,
     
-    /// CHECK: Burn account for deflationary mechanism
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    /// Mint of the payment token, burned from for the burn share
     #[account(
         mut,
-        constraint = burn_account.key() == spl_token::ID
+        address = vault_state.payment_mint @ VaultError::InvalidAccountData,
+        constraint = payment_mint.key() == caller_token_account.mint @ VaultError::InvalidAccountData
     )]
-    pub burn_account: AccountInfo<'info>,
+    pub payment_mint: Account<'info, Mint>,
+    
+    /// One of the caller's stake positions on the prompt, proving a staked discount tier;
+    /// tiers are met by a single position, so callers pass their largest
+    #[account(
+        seeds = [
+            b"stake",
+            prompt_data.id.as_bytes(),
+            caller.key().as_ref(),
+            &caller_stake.position_index.to_le_bytes()
+        ],
+        bump,
+        constraint = caller_stake.owner == caller.key() @ VaultError::InvalidAccountData,
+        constraint = caller_stake.prompt_id == params.prompt_id @ VaultError::InvalidAccountData
+    )]
+    pub caller_stake: Option<Account<'info, StakeAccount>>,
+    
+    /// Sponsor pool to pay the fee from instead of the caller, when eligible
+    #[account(
+        mut,
+        seeds = [b"sponsor", params.prompt_id.as_bytes(), sponsor_pool.sponsor.as_ref()],
+        bump
+    )]
+    pub sponsor_pool: Option<Box<Account<'info, SponsorPool>>>,
+    
+    /// Caller's draw on `sponsor_pool`; required with it
+    #[account(
+        init_if_needed,
+        payer = caller,
+        space = SponsorUsage::SPACE,
+        seeds = [
+            b"sponsor_usage",
+            sponsor_pool.as_ref().map_or(Pubkey::default(), |pool| pool.key()).as_ref(),
+            caller.key().as_ref()
+        ],
+        bump
+    )]
+    pub sponsor_usage: Option<Box<Account<'info, SponsorUsage>>>,
+    
+    /// CHECK: Sponsor escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"sponsor_escrow"],
+        bump
+    )]
+    pub sponsor_escrow: AccountInfo<'info>,
+    
+    /// Integrator that routed the execution, if any
+    #[account(
+        mut,
+        seeds = [b"integrator", integrator.authority.as_ref()],
+        bump
+    )]
+    pub integrator: Option<Box<Account<'info, IntegratorAccount>>>,
+    
+    /// Integrator's token account; required with `integrator`
+    #[account(mut)]
+    pub integrator_token_account: Option<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", executor.authority.as_ref()],
+        bump,
+        constraint = executor.can_execute() @ VaultError::ExecutorNotActive
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    /// CHECK: Instructions sysvar used to inspect the Ed25519 attestation
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: AccountInfo<'info>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
@@ -86,97 +197,246 @@ pub fn handler(ctx: Context<RecordExecution>, params: RecordExecutionParams) ->
     let vault_state = &ctx.accounts.vault_state;
     let clock = Clock::get()?;
     
+    // Recording is idempotent per execution ID
+    require!(
+        !execution_record.is_initialized(),
+        VaultError::ExecutionRecordAlreadyExists
+    );
+    
+    // Reject expired and replayed attestations
+    require!(clock.slot <= params.expiry_slot, VaultError::AttestationExpired);
+    let execution_nonce = &mut ctx.accounts.execution_nonce;
+    execution_nonce.caller = ctx.accounts.caller.key();
+    execution_nonce.consume(params.nonce)?;
+    
+    // Verify the registered executor's Ed25519 attestation carried in this transaction
+    let executor = &mut ctx.accounts.executor;
+    require!(
+        executor.has_signing_key(&params.signing_key),
+        VaultError::UnauthorizedExecutor
+    );
+    let signing_message = params.signing_message(&ctx.accounts.caller.key());
+    let attested = utils::load_ed25519_signatures(&ctx.accounts.instructions)?
+        .iter()
+        .any(|entry| {
+            entry.pubkey == params.signing_key &&
+                entry.signature == params.signature &&
+                entry.message == signing_message
+        });
+    require!(attested, VaultError::InvalidExecutionSignature);
+    executor.executions_attested = executor.executions_attested.saturating_add(1);
+    
     // Check access permissions
     let caller_token_balance = ctx.accounts.caller_token_account.amount;
     require!(
         prompt_data.has_access(&ctx.accounts.caller.key(), caller_token_balance),
         VaultError::InsufficientTokenBalance
     );
+    let user_usage = &mut ctx.accounts.user_usage;
+    user_usage.user = ctx.accounts.caller.key();
+    user_usage.prompt_id = params.prompt_id.clone();
+    user_usage.record_execution(prompt_data.access_control.daily_usage_limit)?;
+    prompt_data.admit_execution(clock.slot)?;
+    
+    // Calculate and validate fee payment. Failures are attested by the
+    // executor, so the refund is settled here rather than collected and
+    // paid back later.
+    prompt_data.apply_due_price_change(clock.unix_timestamp);
+    let execution_fee = prompt_data.execution_fee(params.input_tokens, params.output_tokens);
+    
+    // Discount for the best tier the caller's stake or balance qualifies for;
+    // the split stays proportional since it is computed on the discounted fee
+    let staked_amount = ctx.accounts.caller_stake
+        .as_ref()
+        .map_or(0, |stake| stake.effective_stake(prompt_data.slash_factor));
+    let discount_bps = vault_state.discount_config.discount_bps(staked_amount, caller_token_balance);
+    let discount_amount = utils::calculate_bps_amount(execution_fee, discount_bps);
+    let execution_fee = execution_fee - discount_amount;
+    let refunded_amount = if params.success {
+        0
+    } else {
+        prompt_data.refund_policy.refund_amount(execution_fee)
+    };
+    let mut fee_breakdown = prompt_data.fee_breakdown(execution_fee - refunded_amount);
+    if params.failure_code == Some(FailureCode::ExecutorFault) {
+        // The creator is not paid for failures the executor caused
+        fee_breakdown.waive_creator_share();
+    }
+    
+    // Give the routing integrator its share, capped by the vault
+    let integrator_authority = ctx.accounts.integrator.as_ref().map(|integrator| integrator.authority);
+    if let Some(integrator) = ctx.accounts.integrator.as_mut() {
+        require!(
+            integrator.authority != ctx.accounts.caller.key(),
+            VaultError::SelfRoutedExecution
+        );
+        require!(
+            ctx.accounts.integrator_token_account
+                .as_ref()
+                .is_some_and(|account| {
+                    account.owner == integrator.authority && account.mint == vault_state.payment_mint
+                }),
+            VaultError::InvalidIntegrator
+        );
+        fee_breakdown.add_integrator_share(
+            integrator.effective_fee_bps(&vault_state.integrator_config),
+            vault_state.integrator_config.fee_source,
+        );
+        integrator.record_execution();
+    }
+    let total_fee = fee_breakdown.total_fee;
+    require!(total_fee <= params.max_fee, VaultError::FeeExceedsMaximum);
     
-    // Calculate and validate fee payment
-    let total_fee = prompt_data.fee_amount;
-    if total_fee > 0 {
+    // Draw the prompt fee from the sponsor pool when it will pay for this
+    // caller. The caller chose the integrator, so pays any share on top.
+    let caller_key = ctx.accounts.caller.key();
+    let caller_surcharge = match vault_state.integrator_config.fee_source {
+        IntegratorFeeSource::OnTop => fee_breakdown.integrator_amount,
+        IntegratorFeeSource::ProtocolShare => 0,
+    };
+    let sponsored_fee = total_fee - caller_surcharge;
+    let sponsor_pool_key = match (ctx.accounts.sponsor_pool.as_mut(), ctx.accounts.sponsor_usage.as_mut()) {
+        (Some(sponsor_pool), Some(sponsor_usage))
+            if sponsor_pool.is_eligible(&caller_key, sponsor_usage.amount_sponsored, sponsored_fee) =>
+        {
+            sponsor_pool.record_sponsorship(sponsored_fee);
+            sponsor_usage.pool = sponsor_pool.key();
+            sponsor_usage.user = caller_key;
+            sponsor_usage.record_sponsorship(sponsored_fee);
+            Some(sponsor_pool.key())
+        }
+        _ => None,
+    };
+    let sponsor_escrow_bump = [ctx.bumps.sponsor_escrow];
+    let sponsor_escrow_seeds: &[&[&[u8]]] = &[&[b"sponsor_escrow", &sponsor_escrow_bump]];
+    let (payer_token_account, payer_authority, payer_seeds) = if sponsor_pool_key.is_some() {
+        // Pool the caller's surcharge with the sponsored fee so it is paid out in one go
+        require!(
+            ctx.accounts.caller_token_account.amount >= caller_surcharge,
+            VaultError::InsufficientPayment
+        );
+        fees::transfer_tokens(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.caller_token_account.to_account_info(),
+            &ctx.accounts.sponsor_escrow,
+            &ctx.accounts.caller.to_account_info(),
+            caller_surcharge,
+            &[],
+        )?;
+        (ctx.accounts.sponsor_escrow.clone(), ctx.accounts.sponsor_escrow.clone(), sponsor_escrow_seeds)
+    } else {
         require!(
             ctx.accounts.caller_token_account.amount >= total_fee,
             VaultError::InsufficientPayment
         );
-        
-        // Calculate fee distribution
-        let (creator_amount, dao_amount, validator_amount, burn_amount) = 
-            prompt_data.calculate_fee_distribution(total_fee);
-        
-        // Transfer to creator
-        if creator_amount > 0 {
-            let transfer_to_creator = Transfer {
-                from: ctx.accounts.caller_token_account.to_account_info(),
-                to: ctx.accounts.author_token_account.to_account_info(),
-                authority: ctx.accounts.caller.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    transfer_to_creator,
-                ),
-                creator_amount,
-            )?;
-        }
-        
-        // Transfer to DAO treasury
-        if dao_amount > 0 {
-            let transfer_to_treasury = Transfer {
-                from: ctx.accounts.caller_token_account.to_account_info(),
-                to: ctx.accounts.treasury_token_account.to_account_info(),
-                authority: ctx.accounts.caller.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new(
-                    ctx.accounts.token_program.to_account_info(),
-                    transfer_to_treasury,
-                ),
-                dao_amount,
-            )?;
-        }
-        
-        // Transfer to validator pool
-        if validator_amount > 0 {
-            // Note: In a full implementation, this would transfer to a validator pool account
-            // For now, we'll just log the amount
-            msg!("Validator reward: {} tokens", validator_amount);
-        }
-        
-        // Burn tokens for deflationary mechanism
-        if burn_amount > 0 {
-            // Note: In a full implementation, this would burn tokens
-            // For now, we'll just log the amount
-            msg!("Burned: {} tokens", burn_amount);
-        }
-        
-        msg!("Fee distribution - Creator: {}, DAO: {}, Validators: {}, Burned: {}", 
-             creator_amount, dao_amount, validator_amount, burn_amount);
+        (
+            ctx.accounts.caller_token_account.to_account_info(),
+            ctx.accounts.caller.to_account_info(),
+            &[] as &[&[&[u8]]],
+        )
+    };
+    let challenge_window = vault_state.dispute_config.challenge_window;
+    let validator_quorum = prompt_data.validator_quorum;
+    
+    if challenge_window > 0 || validator_quorum > 0 {
+        // Hold the fee in escrow until the challenge window closes
+        fees::transfer_tokens(
+            &ctx.accounts.token_program.to_account_info(),
+            &payer_token_account,
+            &ctx.accounts.execution_escrow,
+            &payer_authority,
+            total_fee,
+            payer_seeds,
+        )?;
+        // Keep the executor's bond in place until the record settles
+        executor.open_settlement();
+        msg!("Fee of {} escrowed pending settlement", total_fee);
+    } else {
+        let integrator_token_account = ctx.accounts.integrator_token_account
+            .as_ref()
+            .map(|account| account.to_account_info());
+        fees::distribute_fee(
+            &ctx.accounts.token_program.to_account_info(),
+            &payer_token_account,
+            &payer_authority,
+            &FeeRecipients {
+                author_token_account: &ctx.accounts.author_token_account.to_account_info(),
+                treasury_token_account: &ctx.accounts.treasury_token_account.to_account_info(),
+                validator_pool: &ctx.accounts.validator_pool,
+                payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                integrator_token_account: integrator_token_account.as_ref(),
+            },
+            &fee_breakdown,
+            payer_seeds,
+        )?;
+        fees::credit_integrator(
+            ctx.accounts.integrator.as_deref_mut().map(|integrator| &mut **integrator),
+            fee_breakdown.integrator_amount,
+        )?;
     }
     
     // Create execution record
-    *execution_record = ExecutionRecord::new(
-        params.prompt_id.clone(),
-        ctx.accounts.caller.key(),
-        params.version,
-        params.input_hash,
-        params.output_hash,
-        params.signature,
-        params.execution_time_ms,
-        params.success,
-        params.error_message,
-    );
+    execution_record.set_inner(ExecutionRecord::new(&params, caller_key, executor.authority));
+    execution_record.fee_breakdown = fee_breakdown;
+    execution_record.integrator = integrator_authority;
+    execution_record.discount_amount = discount_amount;
+    execution_record.sponsor_pool = sponsor_pool_key;
+    execution_record.sponsored_amount = if sponsor_pool_key.is_some() { sponsored_fee } else { 0 };
+    execution_record.refunded_amount = refunded_amount;
+    execution_record.required_attestations = validator_quorum;
+    if validator_quorum > 0 {
+        // Challenge window starts once the quorum has attested
+        execution_record.await_quorum(vault_state.registry_config.attestation_window, clock.unix_timestamp);
+    } else if challenge_window > 0 {
+        execution_record.open_challenge_window(challenge_window, clock.unix_timestamp);
+    }
     
     // Update prompt statistics
     prompt_data.record_execution(
-        params.execution_time_ms as u32,
+        params.execution_time_ms,
         params.success,
-        total_fee,
+        params.failure_code,
     );
     
-    msg!("Execution recorded for prompt: {}", params.prompt_id);
-    msg!("Caller: {}", ctx.accounts.caller.key());
+    // Update windowed analytics
+    let prompt_analytics = &mut ctx.accounts.prompt_analytics;
+    if !prompt_analytics.is_initialized() {
+        prompt_analytics.initialize(params.prompt_id.clone());
+    }
+    prompt_analytics.record_execution(params.success, params.execution_time_ms);
+    
+    // Escrowed fees count as revenue when they are released, not while they can still be refunded
+    if params.success && execution_record.status == ExecutionStatus::Finalized {
+        let revenue = execution_record.fee_breakdown.revenue();
+        prompt_data.record_revenue(revenue);
+        prompt_analytics.record_revenue(revenue);
+    }
+    
+    emit!(ExecutionRecorded {
+        execution_record: execution_record.key(),
+        prompt_id: execution_record.prompt_id.clone(),
+        execution_id: execution_record.execution_id.clone(),
+        caller: execution_record.caller,
+        executor: execution_record.executor,
+        version: execution_record.version.clone(),
+        input_hash: execution_record.input_hash,
+        output_hash: execution_record.output_hash,
+        execution_time_ms: execution_record.execution_time_ms,
+        success: execution_record.success,
+        failure_code: execution_record.failure_code,
+        input_tokens: execution_record.input_tokens,
+        output_tokens: execution_record.output_tokens,
+        integrator: execution_record.integrator,
+        discount_amount,
+        sponsor_pool: sponsor_pool_key,
+        refunded_amount,
+        fee_breakdown: execution_record.fee_breakdown.clone(),
+        status: execution_record.status,
+        timestamp: execution_record.timestamp,
+    });
+    
+    msg!("Execution {} recorded for prompt: {}", params.execution_id, params.prompt_id);
+    msg!("Caller: {}, executor: {}", ctx.accounts.caller.key(), executor.authority);
     msg!("Success: {}", params.success);
     msg!("Execution time: {}ms", params.execution_time_ms);
     
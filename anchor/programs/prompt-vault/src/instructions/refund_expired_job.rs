@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::JobRefunded;
+
+#[derive(Accounts)]
+pub struct RefundExpiredJob<'info> {
+    #[account(
+        mut,
+        constraint = job.is_expired(Clock::get()?.unix_timestamp) @ VaultError::JobNotExpired,
+        close = caller
+    )]
+    pub job: Account<'info, ExecutionJob>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", job.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        mut,
+        seeds = [b"usage", job.prompt_id.as_bytes(), job.caller.as_ref()],
+        bump
+    )]
+    pub user_usage: Account<'info, UserUsage>,
+    
+    /// Executor that claimed the job; required if it was claimed
+    #[account(
+        mut,
+        seeds = [b"executor", job.executor.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub executor: Option<Account<'info, ExecutorAccount>>,
+    
+    /// CHECK: Caller receives the escrow and the job account rent
+    #[account(
+        mut,
+        constraint = caller.key() == job.caller @ VaultError::UnauthorizedCaller
+    )]
+    pub caller: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == job.caller
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundExpiredJob>) -> Result<()> {
+    let job = &ctx.accounts.job;
+    let escrow = ctx.accounts.execution_escrow.clone();
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &escrow,
+        &ctx.accounts.caller_token_account.to_account_info(),
+        &escrow,
+        job.escrowed_amount,
+        escrow_seeds,
+    )?;
+    
+    // The job never ran, so it gives back its cap slot and daily quota
+    ctx.accounts.prompt_data.release_execution();
+    ctx.accounts.user_usage.release_execution(job.created_at);
+    if job.executor.is_some() {
+        ctx.accounts.executor
+            .as_mut()
+            .ok_or(VaultError::InvalidAccountData)?
+            .close_settlement();
+    }
+    
+    emit!(JobRefunded {
+        job: job.key(),
+        caller: job.caller,
+        refunded_amount: job.escrowed_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Expired job {} refunded: {}", job.job_id, job.escrowed_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::UnattestedExecutionRefunded;
+
+#[derive(Accounts)]
+pub struct RefundUnattestedExecution<'info> {
+    #[account(
+        mut,
+        constraint = execution_record.status == ExecutionStatus::AwaitingQuorum @ VaultError::ExecutionNotAwaitingQuorum,
+        constraint = execution_record.is_attestation_overdue(Clock::get()?.unix_timestamp) @ VaultError::AttestationWindowNotElapsed
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        seeds = [b"prompt", execution_record.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", execution_record.executor.as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == execution_record.caller
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    /// Sponsor pool that paid the fee; required if the record has one
+    #[account(
+        mut,
+        constraint = execution_record.sponsor_pool == Some(sponsor_pool.key()) @ VaultError::InvalidSponsorPool
+    )]
+    pub sponsor_pool: Option<Account<'info, SponsorPool>>,
+    
+    /// Caller's draw on `sponsor_pool`; required with it
+    #[account(
+        mut,
+        seeds = [
+            b"sponsor_usage",
+            execution_record.sponsor_pool.unwrap_or_default().as_ref(),
+            execution_record.caller.as_ref()
+        ],
+        bump
+    )]
+    pub sponsor_usage: Option<Box<Account<'info, SponsorUsage>>>,
+    
+    /// CHECK: Sponsor escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"sponsor_escrow"],
+        bump
+    )]
+    pub sponsor_escrow: Option<AccountInfo<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RefundUnattestedExecution>) -> Result<()> {
+    let escrow = ctx.accounts.execution_escrow.clone();
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    let refund_amount = ctx.accounts.execution_record.fee_breakdown.total_fee;
+    
+    // Refund the escrowed fee to whoever paid it
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let sponsor = match (
+        ctx.accounts.sponsor_pool.as_mut(),
+        ctx.accounts.sponsor_usage.as_mut(),
+        ctx.accounts.sponsor_escrow.as_ref(),
+    ) {
+        (Some(sponsor_pool), Some(sponsor_usage), Some(sponsor_escrow)) => Some(fees::SponsorRefund {
+            sponsor_pool,
+            sponsor_usage,
+            sponsor_escrow,
+        }),
+        _ => None,
+    };
+    fees::refund_execution_fee(
+        &token_program,
+        &escrow,
+        escrow_seeds,
+        &ctx.accounts.execution_record,
+        &ctx.accounts.caller_token_account.to_account_info(),
+        sponsor,
+    )?;
+    
+    // Remaining accounts: executor accounts of the validators that did attest, released unpaid
+    let execution_record = &mut ctx.accounts.execution_record;
+    fees::settle_attesters(&execution_record.attesters, ctx.remaining_accounts, 0)?;
+    
+    execution_record.status = ExecutionStatus::Reversed;
+    ctx.accounts.executor.close_settlement();
+    
+    emit!(UnattestedExecutionRefunded {
+        execution_record: execution_record.key(),
+        prompt_id: execution_record.prompt_id.clone(),
+        refund_amount,
+        attestations: execution_record.attesters.len() as u8,
+        required_attestations: execution_record.required_attestations,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Unattested execution refunded for prompt: {}", execution_record.prompt_id);
+    msg!("Attestations: {}/{}, refunded: {}",
+         execution_record.attesters.len(), execution_record.required_attestations, refund_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutorRegistered;
+
+#[derive(Accounts)]
+pub struct RegisterExecutor<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ExecutorAccount::SPACE,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Executor bond pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"executor_bond_pool"],
+        bump
+    )]
+    pub bond_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterExecutor>, params: RegisterExecutorParams) -> Result<()> {
+    // Validate parameters
+    params.validate(ctx.accounts.vault_state.registry_config.min_executor_bond)?;
+    
+    let executor = &mut ctx.accounts.executor;
+    let clock = Clock::get()?;
+    
+    executor.authority = ctx.accounts.authority.key();
+    executor.role = params.role;
+    executor.status = ExecutorStatus::Active;
+    executor.signing_keys = params.signing_keys;
+    executor.metadata_uri = params.metadata_uri;
+    executor.bond_amount = params.bond_amount;
+    executor.executions_attested = 0;
+    executor.pending_rewards = 0;
+    executor.open_settlements = 0;
+    executor.registered_at = clock.unix_timestamp;
+    executor.exit_requested_at = 0;
+    executor.last_updated = clock.unix_timestamp;
+    ctx.accounts.vault_state.update_active_validators(false, executor.can_validate());
+    
+    // Post the bond
+    let transfer_to_pool = Transfer {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        to: ctx.accounts.bond_pool.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_to_pool,
+        ),
+        params.bond_amount,
+    )?;
+    
+    emit!(ExecutorRegistered {
+        executor: executor.key(),
+        authority: executor.authority,
+        role: executor.role,
+        bond_amount: executor.bond_amount,
+        signing_keys: executor.signing_keys.clone(),
+        timestamp: executor.registered_at,
+    });
+    
+    msg!("Executor registered: {}", ctx.accounts.authority.key());
+    msg!("Bond: {}, signing keys: {}", params.bond_amount, executor.signing_keys.len());
+    
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::IntegratorRegistered;
+
+#[derive(Accounts)]
+pub struct RegisterIntegrator<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = IntegratorAccount::SPACE,
+        seeds = [b"integrator", authority.key().as_ref()],
+        bump
+    )]
+    pub integrator: Account<'info, IntegratorAccount>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterIntegrator>, fee_bps: u16) -> Result<()> {
+    // The vault cap is applied per execution, so it can change after registration
+    require!(fee_bps <= 10000, VaultError::InvalidIntegratorFee);
+    
+    let integrator = &mut ctx.accounts.integrator;
+    let clock = Clock::get()?;
+    
+    integrator.authority = ctx.accounts.authority.key();
+    integrator.fee_bps = fee_bps;
+    integrator.total_executions = 0;
+    integrator.total_fees_earned = 0;
+    integrator.created_at = clock.unix_timestamp;
+    integrator.last_updated = clock.unix_timestamp;
+    
+    emit!(IntegratorRegistered {
+        integrator: integrator.key(),
+        authority: integrator.authority,
+        fee_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Integrator registered: {}", integrator.authority);
+    msg!("Requested fee: {}bps", fee_bps);
+    
+    Ok(())
+}
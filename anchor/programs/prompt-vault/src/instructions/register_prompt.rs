@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::PromptRegistered;
 
 #[derive(Accounts)]
 #[instruction(params: RegisterPromptParams)]
@@ -63,10 +64,28 @@ pub fn handler(ctx: Context<RegisterPrompt>, params: RegisterPromptParams) -> Re
     prompt_data.royalty_config = params.royalty_config.unwrap_or_default();
     prompt_data.tags = params.tags;
     prompt_data.access_control = params.access_control;
+    prompt_data.total_staked = 0;
+    prompt_data.slash_factor = SLASH_FACTOR_PRECISION;
+    prompt_data.slash_pending = false;
+    prompt_data.suspended_for_cause = false;
+    prompt_data.validator_quorum = 0;
+    prompt_data.refund_policy = RefundPolicy::None;
     
     // Update vault state
     vault_state.increment_prompt_count();
     
+    emit!(PromptRegistered {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        author: prompt_data.author,
+        version: prompt_data.current_version.clone(),
+        metadata_uri: prompt_data.metadata_uri.clone(),
+        content_hash: params.content_hash,
+        license_type: prompt_data.license_type,
+        fee_amount: prompt_data.fee_amount,
+        timestamp: clock.unix_timestamp,
+    });
+    
     msg!("Prompt registered: {}", params.id);
     msg!("Author: {}", ctx.accounts.author.key());
     msg!("License: {:?}", params.license_type);
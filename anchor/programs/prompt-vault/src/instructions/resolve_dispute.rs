@@ -0,0 +1,325 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{self, FeeRecipients};
+use crate::events::{ExecutorStatusChanged, DisputeResolved};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        constraint = execution_record.status == ExecutionStatus::Disputed @ VaultError::ExecutionNotDisputable
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute", execution_record.key().as_ref()],
+        bump,
+        close = challenger
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    /// CHECK: Challenger receives the dispute account rent
+    #[account(
+        mut,
+        constraint = challenger.key() == dispute.challenger
+    )]
+    pub challenger: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"prompt", execution_record.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        mut,
+        seeds = [b"analytics", execution_record.prompt_id.as_bytes()],
+        bump
+    )]
+    pub prompt_analytics: Box<Account<'info, PromptAnalytics>>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(
+        mut,
+        seeds = [b"executor", execution_record.executor.as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    pub resolver: Signer<'info>,
+    
+    /// CHECK: Execution escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"execution_escrow"],
+        bump
+    )]
+    pub execution_escrow: AccountInfo<'info>,
+    
+    /// CHECK: Executor bond pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"executor_bond_pool"],
+        bump
+    )]
+    pub bond_pool: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = caller_token_account.owner == execution_record.caller
+    )]
+    pub caller_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = challenger_token_account.owner == dispute.challenger
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = author_token_account.owner == prompt_data.author
+    )]
+    pub author_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == vault_state.treasury
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Validator pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"validator_pool"],
+        bump
+    )]
+    pub validator_pool: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        address = vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub payment_mint: Account<'info, Mint>,
+    
+    /// Integrator the execution was routed through; required if the record has one
+    #[account(
+        mut,
+        seeds = [b"integrator", integrator.authority.as_ref()],
+        bump,
+        constraint = execution_record.integrator == Some(integrator.authority) @ VaultError::InvalidIntegrator
+    )]
+    pub integrator: Option<Account<'info, IntegratorAccount>>,
+    
+    #[account(
+        mut,
+        constraint = execution_record.integrator == Some(integrator_token_account.owner) @ VaultError::InvalidIntegrator,
+        constraint = integrator_token_account.mint == vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub integrator_token_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Sponsor pool that paid the fee; required if the record has one
+    #[account(
+        mut,
+        constraint = execution_record.sponsor_pool == Some(sponsor_pool.key()) @ VaultError::InvalidSponsorPool
+    )]
+    pub sponsor_pool: Option<Account<'info, SponsorPool>>,
+    
+    /// Caller's draw on `sponsor_pool`; required with it
+    #[account(
+        mut,
+        seeds = [
+            b"sponsor_usage",
+            execution_record.sponsor_pool.unwrap_or_default().as_ref(),
+            execution_record.caller.as_ref()
+        ],
+        bump
+    )]
+    pub sponsor_usage: Option<Box<Account<'info, SponsorUsage>>>,
+    
+    /// CHECK: Sponsor escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"sponsor_escrow"],
+        bump
+    )]
+    pub sponsor_escrow: Option<AccountInfo<'info>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, uphold: bool) -> Result<()> {
+    let vault_state = &ctx.accounts.vault_state;
+    let dispute = &ctx.accounts.dispute;
+    
+    // A dispute left unresolved past its timeout can be rejected by anyone
+    require!(
+        vault_state.is_dispute_arbiter(&ctx.accounts.resolver.key())
+            || dispute.has_quorum(uphold, vault_state.dispute_config.validator_quorum)
+            || (!uphold && dispute.is_expired(Clock::get()?.unix_timestamp)),
+        VaultError::UnauthorizedArbiter
+    );
+    
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let escrow = ctx.accounts.execution_escrow.clone();
+    let escrow_bump = [ctx.bumps.execution_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"execution_escrow", &escrow_bump]];
+    let fee_breakdown = ctx.accounts.execution_record.fee_breakdown.clone();
+    let bond_amount = dispute.bond_amount;
+    let mut executor_slashed = 0;
+    ctx.accounts.executor.close_settlement();
+    
+    if uphold {
+        // Refund the escrowed fee to whoever paid it
+        let sponsor = match (
+            ctx.accounts.sponsor_pool.as_mut(),
+            ctx.accounts.sponsor_usage.as_mut(),
+            ctx.accounts.sponsor_escrow.as_ref(),
+        ) {
+            (Some(sponsor_pool), Some(sponsor_usage), Some(sponsor_escrow)) => Some(fees::SponsorRefund {
+                sponsor_pool,
+                sponsor_usage,
+                sponsor_escrow,
+            }),
+            _ => None,
+        };
+        fees::refund_execution_fee(
+            &token_program,
+            &escrow,
+            escrow_seeds,
+            &ctx.accounts.execution_record,
+            &ctx.accounts.caller_token_account.to_account_info(),
+            sponsor,
+        )?;
+        
+        // Remaining accounts: attesting validators' executor accounts, released unpaid
+        fees::settle_attesters(
+            &ctx.accounts.execution_record.attesters,
+            ctx.remaining_accounts,
+            0,
+        )?;
+        
+        // Return the challenger's bond
+        fees::transfer_tokens(
+            &token_program,
+            &escrow,
+            &ctx.accounts.challenger_token_account.to_account_info(),
+            &escrow,
+            bond_amount,
+            escrow_seeds,
+        )?;
+        
+        // Slash the executor's bond to the challenger
+        let min_bond = vault_state.registry_config.min_executor_bond;
+        let executor = &mut ctx.accounts.executor;
+        let was_validating = executor.can_validate();
+        let previous_status = executor.status;
+        let slash_amount = executor.slash_bond(bond_amount, min_bond);
+        executor.touch();
+        executor_slashed = slash_amount;
+        let bond_pool_bump = [ctx.bumps.bond_pool];
+        let bond_pool_seeds: &[&[&[u8]]] = &[&[b"executor_bond_pool", &bond_pool_bump]];
+        fees::transfer_tokens(
+            &token_program,
+            &ctx.accounts.bond_pool,
+            &ctx.accounts.challenger_token_account.to_account_info(),
+            &ctx.accounts.bond_pool,
+            slash_amount,
+            bond_pool_seeds,
+        )?;
+        
+        if executor.status != previous_status {
+            ctx.accounts.vault_state.update_active_validators(was_validating, executor.can_validate());
+            emit!(ExecutorStatusChanged {
+                executor: executor.key(),
+                authority: executor.authority,
+                previous_status,
+                new_status: executor.status,
+                timestamp: executor.last_updated,
+            });
+        }
+        
+        ctx.accounts.execution_record.status = ExecutionStatus::Reversed;
+        
+        msg!("Dispute upheld - fee refunded: {}, executor slashed: {}",
+             fee_breakdown.total_fee, slash_amount);
+    } else {
+        // Release the escrowed fee as if the window had closed
+        let integrator_token_account = ctx.accounts.integrator_token_account
+            .as_ref()
+            .map(|account| account.to_account_info());
+        fees::distribute_fee(
+            &token_program,
+            &escrow,
+            &escrow,
+            &FeeRecipients {
+                author_token_account: &ctx.accounts.author_token_account.to_account_info(),
+                treasury_token_account: &ctx.accounts.treasury_token_account.to_account_info(),
+                validator_pool: &ctx.accounts.validator_pool,
+                payment_mint: &ctx.accounts.payment_mint.to_account_info(),
+                integrator_token_account: integrator_token_account.as_ref(),
+            },
+            &fee_breakdown,
+            escrow_seeds,
+        )?;
+        fees::credit_integrator(
+            ctx.accounts.integrator.as_deref_mut(),
+            fee_breakdown.integrator_amount,
+        )?;
+        
+        // Remaining accounts: attesting validators' executor accounts
+        fees::settle_attesters(
+            &ctx.accounts.execution_record.attesters,
+            ctx.remaining_accounts,
+            fee_breakdown.validator_amount,
+        )?;
+        
+        // Forfeit the challenger's bond to the executor
+        fees::transfer_tokens(
+            &token_program,
+            &escrow,
+            &ctx.accounts.bond_pool,
+            &escrow,
+            bond_amount,
+            escrow_seeds,
+        )?;
+        
+        let executor = &mut ctx.accounts.executor;
+        executor.bond_amount = executor.bond_amount.saturating_add(bond_amount);
+        executor.touch();
+        
+        ctx.accounts.execution_record.status = ExecutionStatus::Finalized;
+        if ctx.accounts.execution_record.success {
+            let revenue = fee_breakdown.revenue();
+            ctx.accounts.prompt_data.record_revenue(revenue);
+            ctx.accounts.prompt_analytics.record_revenue(revenue);
+        }
+        
+        msg!("Dispute rejected - challenger bond forfeited: {}", bond_amount);
+    }
+    
+    emit!(DisputeResolved {
+        dispute: ctx.accounts.dispute.key(),
+        execution_record: ctx.accounts.execution_record.key(),
+        resolver: ctx.accounts.resolver.key(),
+        upheld: uphold,
+        fee_amount: fee_breakdown.total_fee,
+        bond_amount,
+        executor_slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Dispute resolved for prompt: {}", ctx.accounts.execution_record.prompt_id);
+    
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::VaultResumed;
 
 #[derive(Accounts)]
 pub struct ResumeOperations<'info> {
@@ -20,8 +21,14 @@ pub fn handler(ctx: Context<ResumeOperations>) -> Result<()> {
     let vault_state = &mut ctx.accounts.vault_state;
     
     vault_state.is_paused = false;
+    vault_state.paused_at = 0;
     vault_state.touch();
     
+    emit!(VaultResumed {
+        admin: ctx.accounts.admin.key(),
+        timestamp: vault_state.last_updated,
+    });
+    
     msg!("Operations resumed by admin: {}", ctx.accounts.admin.key());
     
     Ok(())
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutorStatusChanged;
+
+#[derive(Accounts)]
+pub struct SetExecutorStatus<'info> {
+    #[account(
+        mut,
+        seeds = [b"executor", executor.authority.as_ref()],
+        bump
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.admin == admin.key() @ VaultError::UnauthorizedAdmin
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetExecutorStatus>, status: ExecutorStatus) -> Result<()> {
+    let executor = &mut ctx.accounts.executor;
+    
+    // The admin jails and unjails; exiting is initiated by the executor itself
+    require!(
+        status != ExecutorStatus::Exiting && executor.status != ExecutorStatus::Exiting,
+        VaultError::InvalidStatusTransition
+    );
+    
+    // An unjailed executor must still meet the minimum bond
+    if status == ExecutorStatus::Active {
+        require!(
+            executor.bond_amount >= ctx.accounts.vault_state.registry_config.min_executor_bond,
+            VaultError::ExecutorBondBelowMinimum
+        );
+    }
+    
+    let previous_status = executor.status;
+    let was_validating = executor.can_validate();
+    executor.status = status;
+    executor.touch();
+    ctx.accounts.vault_state.update_active_validators(was_validating, executor.can_validate());
+    
+    emit!(ExecutorStatusChanged {
+        executor: executor.key(),
+        authority: executor.authority,
+        previous_status,
+        new_status: executor.status,
+        timestamp: executor.last_updated,
+    });
+    
+    msg!("Executor {} status updated by admin: {}", executor.authority, ctx.accounts.admin.key());
+    
+    Ok(())
+}
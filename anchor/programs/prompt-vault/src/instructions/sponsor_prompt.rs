@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::SponsorPoolFunded;
+
+#[derive(Accounts)]
+#[instruction(params: SponsorPromptParams)]
+pub struct SponsorPrompt<'info> {
+    #[account(
+        init_if_needed,
+        payer = sponsor,
+        space = SponsorPool::SPACE,
+        seeds = [b"sponsor", params.prompt_id.as_bytes(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+    
+    #[account(
+        seeds = [b"prompt", params.prompt_id.as_bytes()],
+        bump,
+        constraint = prompt_data.is_accessible() @ VaultError::PromptNotAccessible
+    )]
+    pub prompt_data: Account<'info, PromptData>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.is_operational() @ VaultError::VaultPaused
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = sponsor_token_account.owner == sponsor.key(),
+        constraint = sponsor_token_account.mint == vault_state.payment_mint @ VaultError::InvalidAccountData
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Sponsor escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"sponsor_escrow"],
+        bump
+    )]
+    pub sponsor_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SponsorPrompt>, params: SponsorPromptParams) -> Result<()> {
+    // Validate parameters
+    params.validate()?;
+    
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.sponsor_token_account.to_account_info(),
+        &ctx.accounts.sponsor_escrow,
+        &ctx.accounts.sponsor.to_account_info(),
+        params.amount,
+        &[],
+    )?;
+    
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    if !sponsor_pool.is_initialized() {
+        sponsor_pool.sponsor = ctx.accounts.sponsor.key();
+        sponsor_pool.prompt_id = params.prompt_id.clone();
+        sponsor_pool.created_at = Clock::get()?.unix_timestamp;
+    }
+    sponsor_pool.balance = sponsor_pool.balance.saturating_add(params.amount);
+    sponsor_pool.allowlist = params.allowlist;
+    sponsor_pool.per_user_cap = params.per_user_cap;
+    sponsor_pool.touch();
+    
+    emit!(SponsorPoolFunded {
+        sponsor_pool: sponsor_pool.key(),
+        sponsor: sponsor_pool.sponsor,
+        prompt_id: sponsor_pool.prompt_id.clone(),
+        amount: params.amount,
+        balance: sponsor_pool.balance,
+        per_user_cap: sponsor_pool.per_user_cap,
+        allowlist_size: sponsor_pool.allowlist.len() as u8,
+        timestamp: sponsor_pool.last_updated,
+    });
+    
+    msg!("Sponsor pool for prompt {} funded with {}", params.prompt_id, params.amount);
+    msg!("Pool balance: {}", sponsor_pool.balance);
+    
+    Ok(())
+}
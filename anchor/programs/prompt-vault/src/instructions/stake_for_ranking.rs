@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::Staked;
 
 #[derive(Accounts)]
+#[instruction(params: StakeForRankingParams)]
 pub struct StakeForRanking<'info> {
     #[account(
         init,
@@ -12,13 +14,15 @@ pub struct StakeForRanking<'info> {
         seeds = [
             b"stake",
             prompt_data.id.as_bytes(),
-            staker.key().as_ref()
+            staker.key().as_ref(),
+            &params.position_index.to_le_bytes()
         ],
         bump
     )]
     pub stake_account: Account<'info, StakeAccount>,
     
     #[account(
+        mut,
         seeds = [b"prompt", prompt_data.id.as_bytes()],
         bump,
         constraint = prompt_data.is_active() @ VaultError::PromptNotActive
@@ -54,22 +58,27 @@ pub struct StakeForRanking<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<StakeForRanking>, amount: u64) -> Result<()> {
-    require!(amount > 0, VaultError::StakeAmountBelowMinimum);
+pub fn handler(ctx: Context<StakeForRanking>, params: StakeForRankingParams) -> Result<()> {
+    params.validate()?;
+    ctx.accounts.vault_state.staking_config.check_stake_amount(params.amount)?;
     require!(
-        ctx.accounts.staker_token_account.amount >= amount,
+        ctx.accounts.staker_token_account.amount >= params.amount,
         VaultError::InsufficientStake
     );
     
     let stake_account = &mut ctx.accounts.stake_account;
-    let prompt_data = &ctx.accounts.prompt_data;
+    let prompt_data = &mut ctx.accounts.prompt_data;
     
     // Initialize stake account
-    *stake_account = StakeAccount::new(
+    stake_account.set_inner(StakeAccount::new(
         ctx.accounts.staker.key(),
         prompt_data.id.clone(),
-        amount,
-    );
+        params.position_index,
+        params.amount,
+        params.lockup_duration,
+        prompt_data.slash_factor,
+    ));
+    prompt_data.total_staked = prompt_data.total_staked.saturating_add(params.amount);
     
     // Transfer tokens to stake pool
     let transfer_to_pool = Transfer {
@@ -83,11 +92,23 @@ pub fn handler(ctx: Context<StakeForRanking>, amount: u64) -> Result<()> {
             ctx.accounts.token_program.to_account_info(),
             transfer_to_pool,
         ),
-        amount,
+        params.amount,
     )?;
     
-    msg!("Staked {} tokens for prompt: {}", amount, prompt_data.id);
+    emit!(Staked {
+        stake_account: stake_account.key(),
+        prompt_id: stake_account.prompt_id.clone(),
+        staker: stake_account.owner,
+        position_index: stake_account.position_index,
+        amount: params.amount,
+        position_staked: stake_account.staked_amount,
+        prompt_total_staked: prompt_data.total_staked,
+        lockup_end: stake_account.lockup_end,
+        timestamp: stake_account.last_deposit,
+    });
+    
+    msg!("Staked {} tokens for prompt: {} (position {})", params.amount, prompt_data.id, params.position_index);
     msg!("Staker: {}", ctx.accounts.staker.key());
     
     Ok(())
-} 
\ No newline at end of file
+}
\ No newline at end of file
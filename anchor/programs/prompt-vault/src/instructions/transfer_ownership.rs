@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::OwnershipTransferred;
 
 #[derive(Accounts)]
 pub struct TransferOwnership<'info> {
@@ -28,6 +29,14 @@ pub fn handler(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()>
     prompt_data.author = new_owner;
     prompt_data.touch();
     
+    emit!(OwnershipTransferred {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        previous_owner: old_owner,
+        new_owner,
+        timestamp: prompt_data.last_updated,
+    });
+    
     msg!("Ownership transferred from {} to {}", old_owner, new_owner);
     
     Ok(())
@@ -2,15 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::events::Unstaked;
 
 #[derive(Accounts)]
+#[instruction(position_index: u16)]
 pub struct Unstake<'info> {
     #[account(
         mut,
         seeds = [
             b"stake",
             prompt_data.id.as_bytes(),
-            staker.key().as_ref()
+            staker.key().as_ref(),
+            &position_index.to_le_bytes()
         ],
         bump,
         constraint = stake_account.owner == staker.key() @ VaultError::UnauthorizedAuthor
@@ -18,8 +21,10 @@ pub struct Unstake<'info> {
     pub stake_account: Account<'info, StakeAccount>,
     
     #[account(
+        mut,
         seeds = [b"prompt", prompt_data.id.as_bytes()],
-        bump
+        bump,
+        constraint = !prompt_data.slash_pending @ VaultError::SlashPending
     )]
     pub prompt_data: Account<'info, PromptData>,
     
@@ -50,21 +55,64 @@ pub struct Unstake<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<Unstake>, _position_index: u16, amount: u64) -> Result<()> {
     let stake_account = &mut ctx.accounts.stake_account;
+    let prompt_data = &mut ctx.accounts.prompt_data;
+    let staking_config = &ctx.accounts.vault_state.staking_config;
+    
+    // Settle rewards earned at the old, pre-slash balance before it shrinks
+    stake_account.settle_rewards(staking_config);
+    stake_account.apply_slashing(prompt_data.slash_factor);
     
     require!(amount > 0, VaultError::StakeAmountBelowMinimum);
     require!(
         stake_account.staked_amount >= amount,
         VaultError::InsufficientStake
     );
+    require!(!stake_account.is_locked(), VaultError::StakeLocked);
+    require!(
+        stake_account.deposit_older_than(staking_config.unstake_cooldown),
+        VaultError::CooldownPeriodNotElapsed
+    );
+    
+    // A partial unstake must leave at least the minimum stake behind
+    let remaining = stake_account.staked_amount - amount;
+    if remaining > 0 {
+        staking_config.check_stake_amount(remaining)?;
+    }
     
     // Remove stake amount
     stake_account.remove_stake(amount)?;
+    prompt_data.total_staked = prompt_data.total_staked.saturating_sub(amount);
+    
+    // Return the unstaked principal from the stake pool
+    let stake_pool_bump = [ctx.bumps.stake_pool];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"stake_pool", &stake_pool_bump]];
+    let transfer_to_staker = Transfer {
+        from: ctx.accounts.stake_pool.to_account_info(),
+        to: ctx.accounts.staker_token_account.to_account_info(),
+        authority: ctx.accounts.stake_pool.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_to_staker,
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    emit!(Unstaked {
+        stake_account: stake_account.key(),
+        prompt_id: stake_account.prompt_id.clone(),
+        staker: stake_account.owner,
+        position_index: stake_account.position_index,
+        amount,
+        position_staked: stake_account.staked_amount,
+        prompt_total_staked: prompt_data.total_staked,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
-    // Transfer tokens back from stake pool
-    // Note: In a full implementation, this would use a PDA signer
-    // For now, we'll just log the transfer
     msg!("Unstaking {} tokens from prompt: {}", amount, stake_account.prompt_id);
     msg!("Remaining stake: {}", stake_account.staked_amount);
     
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutorUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateExecutor<'info> {
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = executor.authority == authority.key() @ VaultError::UnauthorizedExecutor
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Executor bond pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"executor_bond_pool"],
+        bump
+    )]
+    pub bond_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<UpdateExecutor>, params: UpdateExecutorParams) -> Result<()> {
+    let executor = &mut ctx.accounts.executor;
+    params.validate(executor)?;
+    
+    // Update signing keys if provided
+    if let Some(signing_keys) = params.signing_keys {
+        executor.signing_keys = signing_keys;
+    }
+    
+    // Update metadata URI if provided
+    if let Some(metadata_uri) = params.metadata_uri {
+        executor.metadata_uri = metadata_uri;
+    }
+    
+    // Top up the bond if requested
+    if params.additional_bond > 0 {
+        let transfer_to_pool = Transfer {
+            from: ctx.accounts.authority_token_account.to_account_info(),
+            to: ctx.accounts.bond_pool.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_to_pool,
+            ),
+            params.additional_bond,
+        )?;
+        executor.bond_amount = executor.bond_amount.saturating_add(params.additional_bond);
+    }
+    
+    executor.touch();
+    
+    emit!(ExecutorUpdated {
+        executor: executor.key(),
+        authority: executor.authority,
+        signing_keys: executor.signing_keys.clone(),
+        bond_amount: executor.bond_amount,
+        timestamp: executor.last_updated,
+    });
+    
+    msg!("Executor updated: {}", executor.authority);
+    msg!("Bond: {}", executor.bond_amount);
+    
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::IntegratorUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateIntegrator<'info> {
+    #[account(
+        mut,
+        seeds = [b"integrator", authority.key().as_ref()],
+        bump
+    )]
+    pub integrator: Account<'info, IntegratorAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateIntegrator>, fee_bps: u16) -> Result<()> {
+    require!(fee_bps <= 10000, VaultError::InvalidIntegratorFee);
+    
+    let integrator = &mut ctx.accounts.integrator;
+    integrator.fee_bps = fee_bps;
+    integrator.touch();
+    
+    emit!(IntegratorUpdated {
+        integrator: integrator.key(),
+        authority: integrator.authority,
+        fee_bps,
+        timestamp: integrator.last_updated,
+    });
+    
+    msg!("Integrator {} fee updated: {}bps", integrator.authority, fee_bps);
+    
+    Ok(())
+}
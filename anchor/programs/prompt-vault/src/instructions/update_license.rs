@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::utils;
+use crate::events::LicenseUpdated;
 
 #[derive(Accounts)]
 #[instruction(params: UpdateLicenseParams)]
@@ -13,23 +15,26 @@ pub struct UpdateLicense<'info> {
     )]
     pub prompt_data: Account<'info, PromptData>,
     
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
     #[account(mut)]
     pub author: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<UpdateLicense>, params: UpdateLicenseParams) -> Result<()> {
     let prompt_data = &mut ctx.accounts.prompt_data;
+    let now = Clock::get()?.unix_timestamp;
+    prompt_data.apply_due_price_change(now);
     
     // Update license type if provided
     if let Some(license_type) = params.license_type {
         prompt_data.license_type = license_type;
     }
     
-    // Update fee amount if provided
-    if let Some(fee_amount) = params.fee_amount {
-        prompt_data.fee_amount = fee_amount;
-    }
-    
     // Update token gate if provided
     if let Some(token_gate) = params.token_gate {
         prompt_data.token_gate = token_gate;
@@ -37,16 +42,73 @@ pub fn handler(ctx: Context<UpdateLicense>, params: UpdateLicenseParams) -> Resu
     
     // Update access control if provided
     if let Some(access_control) = params.access_control {
+        require!(
+            utils::validate_access_control(&access_control),
+            VaultError::InvalidAccessControl
+        );
         prompt_data.access_control = access_control;
     }
     
-    // Update royalty config if provided
-    if let Some(royalty_config) = params.royalty_config {
-        prompt_data.royalty_config = royalty_config;
+    // Update pricing, royalty split, validator quorum and refund policy if
+    // provided, building on any change still pending. Increases, refund cuts
+    // and split or quorum changes only take effect after the vault's notice
+    // period.
+    if params.fee_amount.is_some() ||
+        params.metered_pricing.is_some() ||
+        params.royalty_config.is_some() ||
+        params.validator_quorum.is_some() ||
+        params.refund_policy.is_some()
+    {
+        let scheduled = prompt_data.scheduled_price_terms();
+        let terms = PriceTerms {
+            fee_amount: params.fee_amount.unwrap_or(scheduled.fee_amount),
+            metered_pricing: params.metered_pricing.unwrap_or(scheduled.metered_pricing),
+            royalty_config: params.royalty_config.unwrap_or(scheduled.royalty_config),
+            refund_policy: params.refund_policy.unwrap_or(scheduled.refund_policy),
+            validator_quorum: params.validator_quorum.unwrap_or(scheduled.validator_quorum),
+        };
+        terms.metered_pricing.validate()?;
+        terms.refund_policy.validate()?;
+        let royalty_config = &terms.royalty_config;
+        let total_share_bps = royalty_config.creator_share_bps as u32 + royalty_config.dao_share_bps as u32 +
+            royalty_config.validator_share_bps as u32 + royalty_config.burn_share_bps as u32;
+        require!(total_share_bps == 10000, VaultError::InvalidRoyaltyDistribution);
+        if let Some(validator_quorum) = params.validator_quorum {
+            require!(
+                validator_quorum as usize <= MAX_EXECUTION_ATTESTERS,
+                VaultError::InvalidValidatorQuorum
+            );
+            require!(
+                validator_quorum as u32 <= ctx.accounts.vault_state.active_validators,
+                VaultError::ValidatorQuorumUnreachable
+            );
+        }
+        prompt_data.schedule_price_change(
+            terms,
+            ctx.accounts.vault_state.price_change_notice_period,
+            now,
+        );
+        
+        if let Some(pending) = prompt_data.pending_price_change {
+            msg!("Price change to {} scheduled for {}", pending.fee_amount, pending.effective_at);
+        }
     }
     
     prompt_data.touch();
     
+    emit!(LicenseUpdated {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        license_type: prompt_data.license_type,
+        fee_amount: prompt_data.fee_amount,
+        token_gate: prompt_data.token_gate,
+        validator_quorum: prompt_data.validator_quorum,
+        refund_policy: prompt_data.refund_policy,
+        metered_pricing: prompt_data.metered_pricing,
+        pending_price_change: prompt_data.pending_price_change,
+        timestamp: prompt_data.last_updated,
+    });
+    
     msg!("License updated for prompt: {}", params.prompt_id);
     
     Ok(())
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::MetadataUpdated;
 
 #[derive(Accounts)]
 #[instruction(params: UpdateMetadataParams)]
@@ -37,6 +38,14 @@ pub fn handler(ctx: Context<UpdateMetadata>, params: UpdateMetadataParams) -> Re
     
     prompt_data.touch();
     
+    emit!(MetadataUpdated {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        metadata_uri: prompt_data.metadata_uri.clone(),
+        tags: prompt_data.tags.clone(),
+        timestamp: prompt_data.last_updated,
+    });
+    
     msg!("Metadata updated for prompt: {}", params.prompt_id);
     
     Ok(())
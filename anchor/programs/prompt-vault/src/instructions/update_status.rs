@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::events::PromptStatusChanged;
 
 #[derive(Accounts)]
 #[instruction(params: UpdateStatusParams)]
@@ -9,7 +10,9 @@ pub struct UpdateStatus<'info> {
         mut,
         seeds = [b"prompt", params.prompt_id.as_bytes()],
         bump,
-        constraint = prompt_data.author == author.key() @ VaultError::UnauthorizedAuthor
+        constraint = prompt_data.author == author.key() @ VaultError::UnauthorizedAuthor,
+        constraint = !prompt_data.slash_pending @ VaultError::SlashPending,
+        constraint = !prompt_data.suspended_for_cause @ VaultError::PromptSuspendedForCause
     )]
     pub prompt_data: Account<'info, PromptData>,
     
@@ -20,9 +23,18 @@ pub struct UpdateStatus<'info> {
 pub fn handler(ctx: Context<UpdateStatus>, params: UpdateStatusParams) -> Result<()> {
     let prompt_data = &mut ctx.accounts.prompt_data;
     
+    let previous_status = prompt_data.status;
     prompt_data.status = params.status;
     prompt_data.touch();
     
+    emit!(PromptStatusChanged {
+        prompt: prompt_data.key(),
+        prompt_id: prompt_data.id.clone(),
+        previous_status,
+        new_status: prompt_data.status,
+        timestamp: prompt_data.last_updated,
+    });
+    
     msg!("Status updated for prompt: {} to {:?}", params.prompt_id, params.status);
     
     Ok(())
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::VaultConfigUpdated;
+
+#[derive(Accounts)]
+pub struct UpdateVaultConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"vault_state"],
+        bump,
+        constraint = vault_state.admin == admin.key() @ VaultError::UnauthorizedAdmin
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateVaultConfig>, params: UpdateVaultConfigParams) -> Result<()> {
+    // Validate parameters
+    params.validate()?;
+    
+    let vault_state = &mut ctx.accounts.vault_state;
+    let config_updated = VaultConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        staking_config_updated: params.staking_config.is_some(),
+        slashing_config_updated: params.slashing_config.is_some(),
+        retention_period_updated: params.execution_retention_period.is_some(),
+        registry_config_updated: params.registry_config.is_some(),
+        dispute_config_updated: params.dispute_config.is_some(),
+        job_config_updated: params.job_config.is_some(),
+        price_notice_period_updated: params.price_change_notice_period.is_some(),
+        integrator_config_updated: params.integrator_config.is_some(),
+        discount_config_updated: params.discount_config.is_some(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    
+    // Update staking configuration if provided
+    if let Some(staking_config) = params.staking_config {
+        msg!("Staking config updated - min stake: {}, max stake: {}, reward rate: {}",
+             staking_config.min_stake_amount,
+             staking_config.max_stake_amount,
+             staking_config.reward_rate_per_second);
+        vault_state.staking_config = staking_config;
+    }
+    
+    // Update slashing configuration if provided
+    if let Some(slashing_config) = params.slashing_config {
+        msg!("Slashing config updated - slash: {}bps, dispute window: {}s",
+             slashing_config.slash_bps,
+             slashing_config.dispute_window);
+        vault_state.slashing_config = slashing_config;
+    }
+    
+    // Update execution record retention if provided
+    if let Some(retention_period) = params.execution_retention_period {
+        msg!("Execution retention period updated: {}s", retention_period);
+        vault_state.execution_retention_period = retention_period;
+    }
+    
+    // Update executor registry configuration if provided
+    if let Some(registry_config) = params.registry_config {
+        msg!("Registry config updated - min bond: {}, unbonding period: {}s",
+             registry_config.min_executor_bond,
+             registry_config.unbonding_period);
+        vault_state.registry_config = registry_config;
+    }
+    
+    // Update dispute configuration if provided
+    if let Some(dispute_config) = params.dispute_config {
+        msg!("Dispute config updated - challenge window: {}s, bond: {}, quorum: {}",
+             dispute_config.challenge_window,
+             dispute_config.dispute_bond,
+             dispute_config.validator_quorum);
+        vault_state.dispute_config = dispute_config;
+    }
+    
+    // Update job queue configuration if provided
+    if let Some(job_config) = params.job_config {
+        msg!("Job config updated - timeout: {}s, acceptance window: {}s",
+             job_config.job_timeout,
+             job_config.acceptance_window);
+        vault_state.job_config = job_config;
+    }
+    
+    // Update price increase notice period if provided
+    if let Some(notice_period) = params.price_change_notice_period {
+        msg!("Price change notice period updated: {}s", notice_period);
+        vault_state.price_change_notice_period = notice_period;
+    }
+    
+    // Update integrator fee configuration if provided
+    if let Some(integrator_config) = params.integrator_config {
+        msg!("Integrator config updated - max fee: {}bps", integrator_config.max_fee_bps);
+        vault_state.integrator_config = integrator_config;
+    }
+    
+    // Update fee discount tiers if provided
+    if let Some(discount_config) = params.discount_config {
+        msg!("Discount config updated - {} tiers", discount_config.tiers.len());
+        vault_state.discount_config = discount_config;
+    }
+    
+    vault_state.touch();
+    
+    emit!(config_updated);
+    
+    msg!("Vault config updated by admin: {}", ctx.accounts.admin.key());
+    
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::events::DisputeVoteCast;
+
+#[derive(Accounts)]
+pub struct VoteOnDispute<'info> {
+    #[account(
+        constraint = execution_record.status == ExecutionStatus::Disputed @ VaultError::ExecutionNotDisputable
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+    
+    #[account(
+        mut,
+        seeds = [b"dispute", execution_record.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = validator.can_validate() @ VaultError::ExecutorNotActive,
+        constraint = validator.authority != execution_record.executor @ VaultError::UnauthorizedExecutor,
+        constraint = validator.authority != dispute.challenger @ VaultError::ChallengerCannotVote
+    )]
+    pub validator: Account<'info, ExecutorAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<VoteOnDispute>, uphold: bool) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    
+    dispute.record_vote(ctx.accounts.validator.authority, uphold)?;
+    
+    emit!(DisputeVoteCast {
+        dispute: dispute.key(),
+        validator: ctx.accounts.validator.authority,
+        uphold,
+        uphold_votes: dispute.uphold_votes,
+        reject_votes: dispute.reject_votes,
+    });
+    
+    msg!("Validator {} voted to {} dispute", ctx.accounts.validator.authority,
+         if uphold { "uphold" } else { "reject" });
+    msg!("Votes - uphold: {}, reject: {}", dispute.uphold_votes, dispute.reject_votes);
+    
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::ExecutorBondWithdrawn;
+
+#[derive(Accounts)]
+pub struct WithdrawExecutorBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"executor", authority.key().as_ref()],
+        bump,
+        constraint = executor.authority == authority.key() @ VaultError::UnauthorizedExecutor,
+        constraint = executor.status == ExecutorStatus::Exiting @ VaultError::ExecutorNotExiting,
+        constraint = executor.pending_rewards == 0 @ VaultError::UnclaimedValidatorRewards,
+        constraint = executor.open_settlements == 0 @ VaultError::ExecutorHasOpenSettlements,
+        close = authority
+    )]
+    pub executor: Account<'info, ExecutorAccount>,
+    
+    #[account(
+        seeds = [b"vault_state"],
+        bump
+    )]
+    pub vault_state: Account<'info, VaultState>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key()
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Executor bond pool account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"executor_bond_pool"],
+        bump
+    )]
+    pub bond_pool: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawExecutorBond>) -> Result<()> {
+    let executor = &ctx.accounts.executor;
+    
+    require!(
+        executor.is_unbonded(ctx.accounts.vault_state.registry_config.unbonding_period),
+        VaultError::UnbondingPeriodNotElapsed
+    );
+    
+    let bond_amount = executor.bond_amount;
+    if bond_amount > 0 {
+        let bond_pool_bump = [ctx.bumps.bond_pool];
+        let signer_seeds: &[&[&[u8]]] = &[&[b"executor_bond_pool", &bond_pool_bump]];
+        let transfer_to_authority = Transfer {
+            from: ctx.accounts.bond_pool.to_account_info(),
+            to: ctx.accounts.authority_token_account.to_account_info(),
+            authority: ctx.accounts.bond_pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_to_authority,
+                signer_seeds,
+            ),
+            bond_amount,
+        )?;
+    }
+    
+    emit!(ExecutorBondWithdrawn {
+        executor: executor.key(),
+        authority: executor.authority,
+        amount: bond_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Executor bond withdrawn: {} tokens to {}", bond_amount, executor.authority);
+    
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees;
+use crate::events::SponsorPoolWithdrawn;
+
+#[derive(Accounts)]
+#[instruction(prompt_id: String)]
+pub struct WithdrawSponsorship<'info> {
+    #[account(
+        mut,
+        seeds = [b"sponsor", prompt_id.as_bytes(), sponsor.key().as_ref()],
+        bump
+    )]
+    pub sponsor_pool: Account<'info, SponsorPool>,
+    
+    pub sponsor: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = sponsor_token_account.owner == sponsor.key()
+    )]
+    pub sponsor_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: Sponsor escrow account - validated by seeds
+    #[account(
+        mut,
+        seeds = [b"sponsor_escrow"],
+        bump
+    )]
+    pub sponsor_escrow: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawSponsorship>, _prompt_id: String, amount: u64) -> Result<()> {
+    let sponsor_pool = &mut ctx.accounts.sponsor_pool;
+    require!(amount <= sponsor_pool.balance, VaultError::InsufficientSponsorBalance);
+    
+    let escrow_bump = [ctx.bumps.sponsor_escrow];
+    let escrow_seeds: &[&[&[u8]]] = &[&[b"sponsor_escrow", &escrow_bump]];
+    fees::transfer_tokens(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.sponsor_escrow,
+        &ctx.accounts.sponsor_token_account.to_account_info(),
+        &ctx.accounts.sponsor_escrow,
+        amount,
+        escrow_seeds,
+    )?;
+    
+    sponsor_pool.balance -= amount;
+    sponsor_pool.touch();
+    
+    emit!(SponsorPoolWithdrawn {
+        sponsor_pool: sponsor_pool.key(),
+        sponsor: sponsor_pool.sponsor,
+        prompt_id: sponsor_pool.prompt_id.clone(),
+        amount,
+        balance: sponsor_pool.balance,
+        timestamp: sponsor_pool.last_updated,
+    });
+    
+    msg!("Withdrew {} from sponsor pool for prompt {}", amount, sponsor_pool.prompt_id);
+    
+    Ok(())
+}
@@ -1,16 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use anchor_spl::associated_token::AssociatedToken;
-use sha2::{Digest, Sha256};
 
-declare_id!("PromptVau1tProgramId11111111111111111111111");
+declare_id!("PromptVau1t11111111111111111111111111111111");
 
 pub mod errors;
+pub mod events;
+pub mod fees;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
-use errors::*;
 use instructions::*;
 use state::*;
 
@@ -21,79 +19,57 @@ pub mod prompt_vault {
     /// Initialize the global vault state
     pub fn initialize(
         ctx: Context<Initialize>,
-        admin: Pubkey,
-        fee_bps: u16,
-        min_stake_amount: u64,
+        params: InitializeParams,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, admin, fee_bps, min_stake_amount)
+        instructions::initialize::handler(ctx, params)
     }
 
     /// Register a new prompt
     pub fn register_prompt(
         ctx: Context<RegisterPrompt>,
-        id: String,
-        title: String,
-        description: String,
-        content_uri: String,
-        tags: Vec<String>,
-        license_type: state::LicenseType,
-        price: u64,
+        params: RegisterPromptParams,
     ) -> Result<()> {
-        instructions::register_prompt::handler(
-            ctx, id, title, description, content_uri, tags, license_type, price
-        )
+        instructions::register_prompt::handler(ctx, params)
     }
 
     /// Create a new version of an existing prompt
     pub fn create_version(
         ctx: Context<CreateVersion>,
-        version: String,
-        content_uri: String,
-        changelog: String,
+        params: CreateVersionParams,
     ) -> Result<()> {
-        instructions::create_version::handler(ctx, version, content_uri, changelog)
+        instructions::create_version::handler(ctx, params)
     }
 
     /// Update prompt metadata
     pub fn update_metadata(
         ctx: Context<UpdateMetadata>,
-        title: Option<String>,
-        description: Option<String>,
-        tags: Option<Vec<String>>,
+        params: UpdateMetadataParams,
     ) -> Result<()> {
-        instructions::update_metadata::handler(ctx, title, description, tags)
+        instructions::update_metadata::handler(ctx, params)
     }
 
     /// Update license terms
     pub fn update_license(
         ctx: Context<UpdateLicense>,
-        license_type: state::LicenseType,
-        price: Option<u64>,
-        token_requirement: Option<u64>,
-        whitelist: Option<Vec<Pubkey>>,
+        params: UpdateLicenseParams,
     ) -> Result<()> {
-        instructions::update_license::handler(ctx, license_type, price, token_requirement, whitelist)
+        instructions::update_license::handler(ctx, params)
     }
 
     /// Record prompt execution and handle payments
     pub fn record_execution(
         ctx: Context<RecordExecution>,
-        execution_id: String,
-        success: bool,
-        execution_time_ms: u32,
-        signature: Vec<u8>,
+        params: RecordExecutionParams,
     ) -> Result<()> {
-        instructions::record_execution::handler(
-            ctx, execution_id, success, execution_time_ms, signature
-        )
+        instructions::record_execution::handler(ctx, params)
     }
 
     /// Update prompt status (active, deprecated, etc.)
     pub fn update_status(
         ctx: Context<UpdateStatus>,
-        status: state::PromptStatus,
+        params: UpdateStatusParams,
     ) -> Result<()> {
-        instructions::update_status::handler(ctx, status)
+        instructions::update_status::handler(ctx, params)
     }
 
     /// Transfer prompt ownership
@@ -107,34 +83,43 @@ pub mod prompt_vault {
     /// Fork an existing prompt
     pub fn fork_prompt(
         ctx: Context<ForkPrompt>,
-        new_id: String,
-        title: String,
-        description: String,
+        params: ForkPromptParams,
     ) -> Result<()> {
-        instructions::fork_prompt::handler(ctx, new_id, title, description)
+        instructions::fork_prompt::handler(ctx, params)
     }
 
-    /// Stake tokens for prompt ranking
+    /// Open a new stake position for prompt ranking
     pub fn stake_for_ranking(
         ctx: Context<StakeForRanking>,
+        params: StakeForRankingParams,
+    ) -> Result<()> {
+        instructions::stake_for_ranking::handler(ctx, params)
+    }
+
+    /// Top up an existing stake position
+    pub fn add_stake(
+        ctx: Context<AddStake>,
+        position_index: u16,
         amount: u64,
     ) -> Result<()> {
-        instructions::stake_for_ranking::handler(ctx, amount)
+        instructions::add_stake::handler(ctx, position_index, amount)
     }
 
     /// Unstake tokens
     pub fn unstake(
         ctx: Context<Unstake>,
+        position_index: u16,
         amount: u64,
     ) -> Result<()> {
-        instructions::unstake::handler(ctx, amount)
+        instructions::unstake::handler(ctx, position_index, amount)
     }
 
     /// Claim accumulated rewards
     pub fn claim_rewards(
         ctx: Context<ClaimRewards>,
+        position_index: u16,
     ) -> Result<()> {
-        instructions::claim_rewards::handler(ctx)
+        instructions::claim_rewards::handler(ctx, position_index)
     }
 
     /// Emergency pause (admin only)
@@ -150,4 +135,267 @@ pub mod prompt_vault {
     ) -> Result<()> {
         instructions::resume_operations::handler(ctx)
     }
+
+    /// Withdraw stake principal while the vault is paused, forfeiting rewards
+    /// (unavailable while a slash is pending on the prompt)
+    pub fn emergency_withdraw(
+        ctx: Context<EmergencyWithdraw>,
+        position_index: u16,
+    ) -> Result<()> {
+        instructions::emergency_withdraw::handler(ctx, position_index)
+    }
+
+    /// Update vault configuration such as staking economics (admin only)
+    pub fn update_vault_config(
+        ctx: Context<UpdateVaultConfig>,
+        params: UpdateVaultConfigParams,
+    ) -> Result<()> {
+        instructions::update_vault_config::handler(ctx, params)
+    }
+
+    /// Grow a vault state created with the legacy layout to the current one (admin only)
+    pub fn migrate_vault_state(
+        ctx: Context<MigrateVaultState>,
+        params: MigrateVaultStateParams,
+    ) -> Result<()> {
+        instructions::migrate_vault_state::handler(ctx, params)
+    }
+
+    /// Suspend a prompt and propose slashing its stake (admin or governance)
+    pub fn propose_slash(
+        ctx: Context<ProposeSlash>,
+        params: ProposeSlashParams,
+    ) -> Result<()> {
+        instructions::propose_slash::handler(ctx, params)
+    }
+
+    /// Cancel a pending slash during its dispute window (admin or governance)
+    pub fn cancel_slash(
+        ctx: Context<CancelSlash>,
+    ) -> Result<()> {
+        instructions::cancel_slash::handler(ctx)
+    }
+
+    /// Execute a slash once its dispute window has elapsed
+    pub fn execute_slash(
+        ctx: Context<ExecuteSlash>,
+    ) -> Result<()> {
+        instructions::execute_slash::handler(ctx)
+    }
+
+    /// Reactivate a prompt suspended by a slash once no slash is pending (admin or governance)
+    pub fn lift_suspension(
+        ctx: Context<LiftSuspension>,
+    ) -> Result<()> {
+        instructions::lift_suspension::handler(ctx)
+    }
+
+    /// Close a fully unstaked and claimed stake position to reclaim rent
+    pub fn close_stake_account(
+        ctx: Context<CloseStakeAccount>,
+        position_index: u16,
+    ) -> Result<()> {
+        instructions::close_stake_account::handler(ctx, position_index)
+    }
+
+    /// Close an execution record past the retention window, refunding its payer
+    pub fn close_execution_record(
+        ctx: Context<CloseExecutionRecord>,
+    ) -> Result<()> {
+        instructions::close_execution_record::handler(ctx)
+    }
+
+    /// Register an executor or validator identity and post its bond
+    pub fn register_executor(
+        ctx: Context<RegisterExecutor>,
+        params: RegisterExecutorParams,
+    ) -> Result<()> {
+        instructions::register_executor::handler(ctx, params)
+    }
+
+    /// Update an executor's signing keys, metadata or bond
+    pub fn update_executor(
+        ctx: Context<UpdateExecutor>,
+        params: UpdateExecutorParams,
+    ) -> Result<()> {
+        instructions::update_executor::handler(ctx, params)
+    }
+
+    /// Jail or unjail an executor (admin only)
+    pub fn set_executor_status(
+        ctx: Context<SetExecutorStatus>,
+        status: state::ExecutorStatus,
+    ) -> Result<()> {
+        instructions::set_executor_status::handler(ctx, status)
+    }
+
+    /// Start unbonding an executor's bond
+    pub fn begin_executor_exit(
+        ctx: Context<BeginExecutorExit>,
+    ) -> Result<()> {
+        instructions::begin_executor_exit::handler(ctx)
+    }
+
+    /// Withdraw an executor's bond after the unbonding period
+    pub fn withdraw_executor_bond(
+        ctx: Context<WithdrawExecutorBond>,
+    ) -> Result<()> {
+        instructions::withdraw_executor_bond::handler(ctx)
+    }
+
+    /// Release an execution's escrowed fee after the challenge window
+    pub fn finalize_execution(
+        ctx: Context<FinalizeExecution>,
+    ) -> Result<()> {
+        instructions::finalize_execution::handler(ctx)
+    }
+
+    /// Challenge a pending execution by posting a dispute bond
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        evidence_uri: String,
+    ) -> Result<()> {
+        instructions::open_dispute::handler(ctx, evidence_uri)
+    }
+
+    /// Cast a validator vote on an open dispute
+    pub fn vote_on_dispute(
+        ctx: Context<VoteOnDispute>,
+        uphold: bool,
+    ) -> Result<()> {
+        instructions::vote_on_dispute::handler(ctx, uphold)
+    }
+
+    /// Resolve a dispute by arbiter decision or validator quorum; once the dispute
+    /// times out, anyone can resolve it as rejected
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        uphold: bool,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, uphold)
+    }
+
+    /// Attest a pending execution's result as a registered validator, signed
+    /// with one of the validator's signing keys
+    pub fn attest_execution(
+        ctx: Context<AttestExecution>,
+        params: AttestExecutionParams,
+    ) -> Result<()> {
+        instructions::attest_execution::handler(ctx, params)
+    }
+
+    /// Refund an execution's escrowed fee once its attestation deadline passes
+    pub fn refund_unattested_execution(
+        ctx: Context<RefundUnattestedExecution>,
+    ) -> Result<()> {
+        instructions::refund_unattested_execution::handler(ctx)
+    }
+
+    /// Claim validator rewards credited for attested executions
+    pub fn claim_validator_rewards(
+        ctx: Context<ClaimValidatorRewards>,
+    ) -> Result<()> {
+        instructions::claim_validator_rewards::handler(ctx)
+    }
+
+    /// Quote the fee and access decision for an execution (simulate to read)
+    pub fn quote_execution(
+        ctx: Context<QuoteExecution>,
+        prompt_id: String,
+    ) -> Result<ExecutionQuote> {
+        instructions::quote_execution::handler(ctx, prompt_id)
+    }
+
+    /// Check a user's access and remaining quota; callable via CPI
+    pub fn check_access(
+        ctx: Context<CheckAccess>,
+        prompt_id: String,
+    ) -> Result<AccessDecision> {
+        instructions::check_access::handler(ctx, prompt_id)
+    }
+
+    /// Open an execution job, escrowing the prompt's fee
+    pub fn open_job(
+        ctx: Context<OpenJob>,
+        params: OpenJobParams,
+    ) -> Result<()> {
+        instructions::open_job::handler(ctx, params)
+    }
+
+    /// Claim an open job as a registered executor
+    pub fn claim_job(
+        ctx: Context<ClaimJob>,
+    ) -> Result<()> {
+        instructions::claim_job::handler(ctx)
+    }
+
+    /// Submit a claimed job's attested output
+    pub fn fulfil_job(
+        ctx: Context<FulfilJob>,
+        params: FulfilJobParams,
+    ) -> Result<()> {
+        instructions::fulfil_job::handler(ctx, params)
+    }
+
+    /// Accept a fulfilled job and release its escrowed fee (caller only)
+    pub fn accept_job(
+        ctx: Context<SettleJob>,
+    ) -> Result<()> {
+        instructions::accept_job::handler(ctx)
+    }
+
+    /// Release a fulfilled job's fee once the acceptance window has passed
+    pub fn finalize_job(
+        ctx: Context<SettleJob>,
+    ) -> Result<()> {
+        instructions::finalize_job::handler(ctx)
+    }
+
+    /// Dispute a fulfilled job before its acceptance window closes (caller only)
+    pub fn dispute_job(
+        ctx: Context<DisputeJob>,
+        evidence_uri: String,
+    ) -> Result<()> {
+        instructions::dispute_job::handler(ctx, evidence_uri)
+    }
+
+    /// Refund an expired job's escrow to its caller
+    pub fn refund_expired_job(
+        ctx: Context<RefundExpiredJob>,
+    ) -> Result<()> {
+        instructions::refund_expired_job::handler(ctx)
+    }
+
+    /// Register as an integrator earning a share of routed execution fees
+    pub fn register_integrator(
+        ctx: Context<RegisterIntegrator>,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::register_integrator::handler(ctx, fee_bps)
+    }
+
+    /// Change an integrator's requested fee share
+    pub fn update_integrator(
+        ctx: Context<UpdateIntegrator>,
+        fee_bps: u16,
+    ) -> Result<()> {
+        instructions::update_integrator::handler(ctx, fee_bps)
+    }
+
+    /// Fund a pool that pays for other users' executions of a prompt
+    pub fn sponsor_prompt(
+        ctx: Context<SponsorPrompt>,
+        params: SponsorPromptParams,
+    ) -> Result<()> {
+        instructions::sponsor_prompt::handler(ctx, params)
+    }
+
+    /// Withdraw unspent tokens from a sponsor pool
+    pub fn withdraw_sponsorship(
+        ctx: Context<WithdrawSponsorship>,
+        prompt_id: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_sponsorship::handler(ctx, prompt_id, amount)
+    }
 } 
\ No newline at end of file
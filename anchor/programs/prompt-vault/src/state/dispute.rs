@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Challenge against an execution record's reported result
+#[account]
+pub struct Dispute {
+    /// Execution record under dispute
+    pub execution_record: Pubkey,
+    /// Party that opened the dispute and posted the bond
+    pub challenger: Pubkey,
+    /// Bond posted by the challenger
+    pub bond_amount: u64,
+    /// URI of the challenger's evidence
+    pub evidence_uri: String,
+    /// When the dispute was opened
+    pub opened_at: i64,
+    /// Anyone can resolve the dispute as rejected from this timestamp
+    pub resolve_deadline: i64,
+    /// Validators that have voted
+    pub voters: Vec<Pubkey>,
+    /// Votes to uphold the dispute
+    pub uphold_votes: u8,
+    /// Votes to reject the dispute
+    pub reject_votes: u8,
+}
+
+impl Dispute {
+    pub const SPACE: usize = DISPUTE_SIZE;
+
+    /// Initialize a dispute against `execution_record`
+    pub fn open(
+        &mut self,
+        execution_record: Pubkey,
+        challenger: Pubkey,
+        bond_amount: u64,
+        evidence_uri: String,
+        dispute_timeout: i64,
+    ) -> Result<()> {
+        require!(evidence_uri.len() <= 256, crate::errors::VaultError::EvidenceUriTooLong);
+        
+        self.execution_record = execution_record;
+        self.challenger = challenger;
+        self.bond_amount = bond_amount;
+        self.evidence_uri = evidence_uri;
+        self.opened_at = Clock::get()?.unix_timestamp;
+        self.resolve_deadline = self.opened_at.saturating_add(dispute_timeout);
+        self.voters = Vec::new();
+        self.uphold_votes = 0;
+        self.reject_votes = 0;
+        Ok(())
+    }
+
+    /// Record a validator vote
+    pub fn record_vote(&mut self, validator: Pubkey, uphold: bool) -> Result<()> {
+        require!(!self.voters.contains(&validator), crate::errors::VaultError::AlreadyVoted);
+        require!(self.voters.len() < MAX_DISPUTE_VOTERS, crate::errors::VaultError::AlreadyVoted);
+        
+        self.voters.push(validator);
+        if uphold {
+            self.uphold_votes = self.uphold_votes.saturating_add(1);
+        } else {
+            self.reject_votes = self.reject_votes.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    /// Check if the dispute outlived its timeout without being resolved
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.resolve_deadline
+    }
+
+    /// Check if validators reached quorum for the given outcome
+    pub fn has_quorum(&self, uphold: bool, quorum: u8) -> bool {
+        let votes = if uphold { self.uphold_votes } else { self.reject_votes };
+        quorum > 0 && votes >= quorum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_dispute() -> Dispute {
+        Dispute {
+            execution_record: Pubkey::new_unique(),
+            challenger: Pubkey::new_unique(),
+            bond_amount: 100,
+            evidence_uri: String::new(),
+            opened_at: 1000,
+            resolve_deadline: 2000,
+            voters: Vec::new(),
+            uphold_votes: 0,
+            reject_votes: 0,
+        }
+    }
+    
+    #[test]
+    fn test_record_vote() {
+        let mut dispute = test_dispute();
+        let validators: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        dispute.record_vote(validators[0], true).unwrap();
+        assert!(dispute.record_vote(validators[0], false).is_err()); // one vote per validator
+        assert!(!dispute.has_quorum(true, 2));
+        
+        dispute.record_vote(validators[1], true).unwrap();
+        dispute.record_vote(validators[2], false).unwrap();
+        assert!(dispute.has_quorum(true, 2));
+        assert!(!dispute.has_quorum(false, 2));
+        assert!(!dispute.has_quorum(true, 0)); // without a quorum only the arbiter resolves
+        
+        for _ in validators.len()..MAX_DISPUTE_VOTERS {
+            dispute.record_vote(Pubkey::new_unique(), false).unwrap();
+        }
+        assert!(dispute.record_vote(Pubkey::new_unique(), false).is_err());
+    }
+    
+    #[test]
+    fn test_dispute_timeout() {
+        let dispute = test_dispute();
+        assert!(!dispute.is_expired(1999));
+        assert!(dispute.is_expired(2000));
+    }
+}
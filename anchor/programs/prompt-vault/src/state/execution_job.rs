@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+use super::*;
+
+/// Domain separator prefixed to every job fulfilment attestation preimage
+pub const JOB_SIGNATURE_DOMAIN: &[u8] = b"prompthub:job:v1";
+
+/// Lifecycle of an execution job
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JobStatus {
+    /// Fee escrowed, waiting for an executor
+    #[default]
+    Open = 0,
+    /// Claimed by an executor
+    Claimed = 1,
+    /// Output submitted, waiting for the caller to accept or dispute
+    Fulfilled = 2,
+    /// Fee released; job is final
+    Settled = 3,
+}
+
+/// Execution request with an escrowed fee, fulfilled by a registered executor
+#[account]
+pub struct ExecutionJob {
+    /// Prompt to execute
+    pub prompt_id: String,
+    /// Client-supplied ID that keys the job's PDA
+    pub job_id: String,
+    /// Caller that opened the job and paid the fee
+    pub caller: Pubkey,
+    /// Version of the prompt when the job was opened
+    pub version: String,
+    /// Hash of the input data
+    pub input_hash: [u8; 32],
+    /// Fee held in escrow
+    pub escrowed_amount: u64,
+    /// Current status
+    pub status: JobStatus,
+    /// When the job was opened
+    pub created_at: i64,
+    /// Deadline for the job to be fulfilled
+    pub expires_at: i64,
+    /// Executor that claimed the job
+    pub executor: Option<Pubkey>,
+    /// Hash of the output data
+    pub output_hash: [u8; 32],
+    /// Executor's Ed25519 signature over the fulfilment
+    pub signature: [u8; 64],
+    /// Execution time in milliseconds
+    pub execution_time_ms: u64,
+    /// Attested input token count the fee was metered on
+    pub input_tokens: u64,
+    /// Attested output token count the fee was metered on
+    pub output_tokens: u64,
+    /// Whether the execution was successful
+    pub success: bool,
+    /// Cause of the failure (None on success)
+    pub failure_code: Option<FailureCode>,
+    /// Part of the fee refunded under the prompt's refund policy
+    pub refunded_amount: u64,
+    /// Fee charged and how it is split, fixed at fulfilment
+    pub fee_breakdown: FeeBreakdown,
+    /// Deadline for the caller to accept or dispute before the job finalizes on its own
+    pub acceptance_deadline: i64,
+}
+
+impl ExecutionJob {
+    pub const SPACE: usize = EXECUTION_JOB_SIZE;
+
+    /// Check if the job passed its deadline without being fulfilled
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.status, JobStatus::Open | JobStatus::Claimed) && now > self.expires_at
+    }
+
+    /// Check if the caller can still dispute a fulfilled job
+    pub fn is_disputable(&self, now: i64) -> bool {
+        self.status == JobStatus::Fulfilled && now < self.acceptance_deadline
+    }
+
+    /// Check if a fulfilled job can be finalized without the caller
+    pub fn is_finalizable(&self, now: i64) -> bool {
+        self.status == JobStatus::Fulfilled && now >= self.acceptance_deadline
+    }
+
+    /// Escrowed amount returned to the caller on settlement
+    pub fn refund_amount(&self) -> u64 {
+        self.escrowed_amount.saturating_sub(self.fee_breakdown.total_fee)
+    }
+}
+
+/// Parameters for opening an execution job
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OpenJobParams {
+    /// Prompt ID
+    pub prompt_id: String,
+    /// Job ID, unique per prompt and caller
+    pub job_id: String,
+    /// Hash of input data
+    pub input_hash: [u8; 32],
+    /// Most the caller agrees to escrow; guards against price changes in flight
+    pub max_fee: u64,
+}
+
+impl OpenJobParams {
+    /// Validate the job parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.prompt_id.is_empty(), crate::errors::VaultError::EmptyPromptId);
+        require!(
+            !self.job_id.is_empty() && self.job_id.len() <= MAX_EXECUTION_ID_LEN,
+            crate::errors::VaultError::InvalidExecutionId
+        );
+        Ok(())
+    }
+}
+
+/// Parameters for fulfilling a claimed job
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FulfilJobParams {
+    /// Hash of output data
+    pub output_hash: [u8; 32],
+    /// Executor signing key that attested the result
+    pub signing_key: Pubkey,
+    /// Executor's Ed25519 signature over `signing_message`
+    pub signature: [u8; 64],
+    /// Execution time in milliseconds
+    pub execution_time_ms: u64,
+    /// Input tokens consumed, as attested by the executor
+    pub input_tokens: u64,
+    /// Output tokens produced, as attested by the executor
+    pub output_tokens: u64,
+    /// Whether execution was successful
+    pub success: bool,
+    /// Cause of the failure; required when `success` is false
+    pub failure_code: Option<FailureCode>,
+}
+
+impl FulfilJobParams {
+    /// Validate the fulfilment parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.success == self.failure_code.is_none(),
+            crate::errors::VaultError::InvalidFailureCode
+        );
+        Ok(())
+    }
+
+    /// Domain-separated preimage hash the executor signs
+    pub fn signing_message(&self, job: &Pubkey, input_hash: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(JOB_SIGNATURE_DOMAIN);
+        hasher.update(crate::ID.as_ref());
+        hasher.update(job.as_ref());
+        hasher.update(input_hash);
+        hasher.update(self.output_hash);
+        hasher.update(self.execution_time_ms.to_le_bytes());
+        hasher.update(self.input_tokens.to_le_bytes());
+        hasher.update(self.output_tokens.to_le_bytes());
+        hasher.update([self.success as u8]);
+        hasher.update([self.failure_code.map_or(u8::MAX, |code| code as u8)]);
+        
+        hasher.finalize().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_job(status: JobStatus) -> ExecutionJob {
+        ExecutionJob {
+            prompt_id: "prompt".to_string(),
+            job_id: "job".to_string(),
+            caller: Pubkey::new_unique(),
+            version: "1.0.0".to_string(),
+            input_hash: [1; 32],
+            escrowed_amount: 1500,
+            status,
+            created_at: 0,
+            expires_at: 1000,
+            executor: None,
+            output_hash: [0; 32],
+            signature: [0; 64],
+            execution_time_ms: 0,
+            input_tokens: 0,
+            output_tokens: 0,
+            success: false,
+            failure_code: None,
+            refunded_amount: 0,
+            fee_breakdown: FeeBreakdown::default(),
+            acceptance_deadline: 0,
+        }
+    }
+    
+    #[test]
+    fn test_job_deadlines() {
+        let open = test_job(JobStatus::Open);
+        assert!(!open.is_expired(1000));
+        assert!(open.is_expired(1001));
+        assert!(!open.is_disputable(0));
+        
+        // Once fulfilled the job never expires; the caller has until the deadline to dispute
+        let mut fulfilled = test_job(JobStatus::Fulfilled);
+        fulfilled.acceptance_deadline = 2000;
+        assert!(!fulfilled.is_expired(5000));
+        assert!(fulfilled.is_disputable(1999));
+        assert!(!fulfilled.is_finalizable(1999));
+        assert!(!fulfilled.is_disputable(2000));
+        assert!(fulfilled.is_finalizable(2000));
+    }
+    
+    #[test]
+    fn test_disputed_job_becomes_record() {
+        let mut job = test_job(JobStatus::Fulfilled);
+        let executor = Pubkey::new_unique();
+        job.executor = Some(executor);
+        job.success = true;
+        job.output_hash = [2; 32];
+        job.acceptance_deadline = 2000;
+        job.fee_breakdown = FeeBreakdown {
+            total_fee: 1000,
+            creator_amount: 600,
+            dao_amount: 150,
+            validator_amount: 150,
+            burn_amount: 100,
+            integrator_amount: 0,
+        };
+        
+        // The unused escrow goes back up front; the record holds only the charged fee
+        assert_eq!(job.refund_amount(), 500);
+        let record = ExecutionRecord::from_job(&job, executor, 1500);
+        assert!(record.status == ExecutionStatus::Disputed);
+        assert_eq!(record.seed_prefix(), b"job_execution");
+        assert_eq!(record.caller, job.caller);
+        assert_eq!(record.executor, executor);
+        assert_eq!(record.output_hash, job.output_hash);
+        assert_eq!(record.fee_breakdown.total_fee, 1000);
+        assert_eq!(record.timestamp, 1500);
+        assert!(!record.is_disputable(1500));
+        assert!(!record.is_finalizable(2000));
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Replay protection for a caller's signed execution payloads
+///
+/// Nonces may arrive out of order as long as they stay within
+/// `utils::NONCE_WINDOW` of the highest nonce seen.
+#[account]
+pub struct ExecutionNonce {
+    /// Caller the nonces belong to
+    pub caller: Pubkey,
+    /// Highest nonce consumed so far
+    pub highest_nonce: u64,
+    /// Bitmap of consumed nonces below `highest_nonce`
+    pub used_bitmap: u128,
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl ExecutionNonce {
+    pub const SPACE: usize = EXECUTION_NONCE_SIZE;
+
+    /// Consume a nonce, rejecting replays and nonces outside the window
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        let (highest_nonce, used_bitmap) =
+            crate::utils::consume_nonce(self.highest_nonce, self.used_bitmap, nonce)
+                .ok_or(crate::errors::VaultError::ExecutionNonceReused)?;
+        
+        self.highest_nonce = highest_nonce;
+        self.used_bitmap = used_bitmap;
+        self.last_updated = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
@@ -1,13 +1,62 @@
 use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
 use super::*;
 
+/// Domain separator prefixed to every execution attestation preimage
+pub const EXECUTION_SIGNATURE_DOMAIN: &[u8] = b"prompthub:execution:v1";
+
+/// Domain separator prefixed to every validator attestation preimage
+pub const ATTESTATION_SIGNATURE_DOMAIN: &[u8] = b"prompthub:attestation:v1";
+
+/// Settlement status of an execution record
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutionStatus {
+    /// Fee held in escrow while the challenge window is open
+    Pending = 0,
+    /// Under dispute; fee stays in escrow until resolved
+    Disputed = 1,
+    /// Fee distributed; record is final
+    #[default]
+    Finalized = 2,
+    /// Dispute upheld; fee refunded to the caller
+    Reversed = 3,
+    /// Fee held in escrow until the prompt's validator quorum attests
+    AwaitingQuorum = 4,
+}
+
+/// Cause of a failed execution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FailureCode {
+    /// Model did not respond in time
+    ModelTimeout = 0,
+    /// Upstream model provider returned an error
+    ProviderError = 1,
+    /// Output was blocked by a content filter
+    ContentFilter = 2,
+    /// Caller supplied input the prompt cannot run on
+    InvalidInput = 3,
+    /// Executor misbehaved or failed on its own side
+    ExecutorFault = 4,
+    /// Any other cause; see `error_message`
+    Other = 5,
+}
+
+/// Number of `FailureCode` variants
+pub const FAILURE_CODE_COUNT: usize = 6;
+
 /// Execution record for PromptSig verification
 #[account]
 pub struct ExecutionRecord {
     /// Prompt ID that was executed
     pub prompt_id: String,
+    /// Client-supplied ID that keys the record's PDA
+    pub execution_id: String,
     /// Caller who executed the prompt
     pub caller: Pubkey,
+    /// Account that paid the record's rent and receives it back on close
+    pub payer: Pubkey,
+    /// Registered executor whose Ed25519 attestation was verified
+    pub executor: Pubkey,
     /// Version of the prompt that was executed
     pub version: String,
     /// Hash of the input data
@@ -24,59 +73,208 @@ pub struct ExecutionRecord {
     pub success: bool,
     /// Error message if execution failed
     pub error_message: Option<String>,
+    /// Cause of the failure (None on success)
+    pub failure_code: Option<FailureCode>,
+    /// Part of the prompt fee refunded under the prompt's refund policy
+    pub refunded_amount: u64,
+    /// Settlement status
+    pub status: ExecutionStatus,
+    /// End of the challenge window
+    pub challenge_deadline: i64,
+    /// Fee charged for the execution and how it is split
+    pub fee_breakdown: FeeBreakdown,
+    /// Attested input token count the fee was metered on
+    pub input_tokens: u64,
+    /// Attested output token count the fee was metered on
+    pub output_tokens: u64,
+    /// Integrator the execution was routed through, owed `fee_breakdown.integrator_amount`
+    pub integrator: Option<Pubkey>,
+    /// Holder or staker discount taken off the execution fee
+    pub discount_amount: u64,
+    /// Sponsor pool that paid the fee instead of the caller
+    pub sponsor_pool: Option<Pubkey>,
+    /// Part of the fee paid by `sponsor_pool`; the caller paid the rest
+    pub sponsored_amount: u64,
+    /// Validator attestations required before the fee is released
+    pub required_attestations: u8,
+    /// When the fee becomes refundable if the quorum has not attested
+    pub attestation_deadline: i64,
+    /// Validators that attested the recorded hashes
+    pub attesters: Vec<Pubkey>,
+    /// Whether the record was created from a disputed job
+    pub from_job: bool,
 }
 
 impl ExecutionRecord {
     pub const SPACE: usize = EXECUTION_RECORD_SIZE;
 
-    /// Create a new execution record
-    pub fn new(
-        prompt_id: String,
-        caller: Pubkey,
-        version: String,
-        input_hash: [u8; 32],
-        output_hash: [u8; 32],
-        signature: [u8; 64],
-        execution_time_ms: u64,
-        success: bool,
-        error_message: Option<String>,
-    ) -> Self {
+    /// Create a new execution record from the recorded parameters
+    ///
+    /// The caller pays the record's rent, so is also its `payer`.
+    pub fn new(params: &RecordExecutionParams, caller: Pubkey, executor: Pubkey) -> Self {
+        let timestamp = Clock::get().unwrap().unix_timestamp;
         Self {
-            prompt_id,
+            prompt_id: params.prompt_id.clone(),
+            execution_id: params.execution_id.clone(),
             caller,
-            version,
-            input_hash,
-            output_hash,
-            timestamp: Clock::get().unwrap().unix_timestamp,
-            signature,
-            execution_time_ms,
-            success,
-            error_message,
+            payer: caller,
+            executor,
+            version: params.version.clone(),
+            input_hash: params.input_hash,
+            output_hash: params.output_hash,
+            timestamp,
+            signature: params.signature,
+            execution_time_ms: params.execution_time_ms,
+            success: params.success,
+            error_message: params.error_message.clone(),
+            failure_code: params.failure_code,
+            refunded_amount: 0,
+            status: ExecutionStatus::Finalized,
+            challenge_deadline: timestamp,
+            fee_breakdown: FeeBreakdown::default(),
+            input_tokens: params.input_tokens,
+            output_tokens: params.output_tokens,
+            integrator: None,
+            discount_amount: 0,
+            sponsor_pool: None,
+            sponsored_amount: 0,
+            required_attestations: 0,
+            attestation_deadline: 0,
+            attesters: Vec::new(),
+            from_job: false,
+        }
+    }
+
+    /// Create a disputed execution record from a fulfilled job
+    ///
+    /// The record takes over the job's escrowed fee, so the existing dispute
+    /// flow can refund or release it.
+    pub fn from_job(job: &ExecutionJob, executor: Pubkey, timestamp: i64) -> Self {
+        Self {
+            prompt_id: job.prompt_id.clone(),
+            execution_id: job.job_id.clone(),
+            caller: job.caller,
+            payer: job.caller,
+            executor,
+            version: job.version.clone(),
+            input_hash: job.input_hash,
+            output_hash: job.output_hash,
+            timestamp,
+            signature: job.signature,
+            execution_time_ms: job.execution_time_ms,
+            success: job.success,
+            error_message: None,
+            failure_code: job.failure_code,
+            refunded_amount: job.refunded_amount,
+            status: ExecutionStatus::Disputed,
+            challenge_deadline: job.acceptance_deadline,
+            fee_breakdown: job.fee_breakdown.clone(),
+            input_tokens: job.input_tokens,
+            output_tokens: job.output_tokens,
+            integrator: None,
+            discount_amount: 0,
+            sponsor_pool: None,
+            sponsored_amount: 0,
+            required_attestations: 0,
+            attestation_deadline: 0,
+            attesters: Vec::new(),
+            from_job: true,
         }
     }
 
-    /// Verify the execution signature
+    /// PDA seed prefix the record was created under
+    ///
+    /// Job records live under their own prefix so a job ID can never collide
+    /// with an execution ID the caller recorded directly.
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        if self.from_job {
+            b"job_execution"
+        } else {
+            b"execution"
+        }
+    }
+
+    /// Record a validator attestation, returning whether quorum is reached
+    pub fn add_attester(&mut self, validator: Pubkey) -> Result<bool> {
+        require!(!self.attesters.contains(&validator), crate::errors::VaultError::AlreadyAttested);
+        require!(
+            self.attesters.len() < MAX_EXECUTION_ATTESTERS,
+            crate::errors::VaultError::AttesterLimitReached
+        );
+        
+        self.attesters.push(validator);
+        Ok(self.attesters.len() >= self.required_attestations as usize)
+    }
+
+    /// Hold the fee in escrow until the validator quorum attests
+    pub fn await_quorum(&mut self, attestation_window: i64, now: i64) {
+        self.status = ExecutionStatus::AwaitingQuorum;
+        self.attestation_deadline = now.saturating_add(attestation_window);
+    }
+
+    /// Hold the fee in escrow until the challenge window closes
+    pub fn open_challenge_window(&mut self, challenge_window: i64, now: i64) {
+        self.status = ExecutionStatus::Pending;
+        self.challenge_deadline = now.saturating_add(challenge_window);
+    }
+
+    /// Check if the account has been written by `record_execution`
+    pub fn is_initialized(&self) -> bool {
+        self.timestamp != 0
+    }
+
+    /// Check if the record can still be disputed
+    pub fn is_disputable(&self, now: i64) -> bool {
+        self.status == ExecutionStatus::Pending && now < self.challenge_deadline
+    }
+
+    /// Check if the challenge window passed without a dispute
+    pub fn is_finalizable(&self, now: i64) -> bool {
+        self.status == ExecutionStatus::Pending && now >= self.challenge_deadline
+    }
+
+    /// Check if the validator quorum missed its attestation deadline
+    pub fn is_attestation_overdue(&self, now: i64) -> bool {
+        self.status == ExecutionStatus::AwaitingQuorum && now >= self.attestation_deadline
+    }
+
+    /// Part of the escrowed fee the caller paid themselves (the rest came from a sponsor pool)
+    pub fn caller_refund_amount(&self) -> u64 {
+        self.fee_breakdown.total_fee.saturating_sub(self.sponsored_amount)
+    }
+
+    /// Check if the record's fee has been distributed or refunded
+    pub fn is_settled(&self) -> bool {
+        matches!(self.status, ExecutionStatus::Finalized | ExecutionStatus::Reversed)
+    }
+
+    /// Check the record was attested by the expected executor
+    ///
+    /// The Ed25519 signature itself is verified by `record_execution` before the
+    /// record is written, so only the attesting key needs comparing here.
     pub fn verify_signature(&self, expected_signer: &Pubkey) -> bool {
-        // In a real implementation, this would verify the cryptographic signature
-        // For now, we'll do a basic check
-        self.caller == *expected_signer
+        self.executor == *expected_signer
     }
 
     /// Get a hash of the execution data for verification
     pub fn get_execution_hash(&self) -> [u8; 32] {
-        use sha2::{Digest, Sha256};
-        
         let mut hasher = Sha256::new();
         hasher.update(self.prompt_id.as_bytes());
         hasher.update(self.caller.as_ref());
         hasher.update(self.version.as_bytes());
-        hasher.update(&self.input_hash);
-        hasher.update(&self.output_hash);
-        hasher.update(&self.timestamp.to_le_bytes());
+        hasher.update(self.input_hash);
+        hasher.update(self.output_hash);
+        hasher.update(self.timestamp.to_le_bytes());
         
         hasher.finalize().into()
     }
 
+    /// Check if the record has been kept for at least the retention period
+    pub fn is_past_retention(&self, retention_period: i64) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp;
+        current_time.saturating_sub(self.timestamp) >= retention_period
+    }
+
     /// Check if the execution was recent (within last 24 hours)
     pub fn is_recent(&self) -> bool {
         let current_time = Clock::get().unwrap().unix_timestamp;
@@ -84,25 +282,64 @@ impl ExecutionRecord {
     }
 }
 
+/// Fee quote returned by `quote_execution`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecutionQuote {
+    /// Prompt ID
+    pub prompt_id: String,
+    /// Current version that would be executed
+    pub version: String,
+    /// Base fee that would be charged, after any discount, and how it is split
+    pub fee_breakdown: FeeBreakdown,
+    /// Holder or staker discount the caller qualifies for (basis points)
+    pub discount_bps: u16,
+    /// Per-token pricing added to the base fee, up to its max fee
+    pub metered_pricing: MeteredPricing,
+    /// Scheduled price increase not yet in effect
+    pub pending_price_change: Option<PendingPriceChange>,
+    /// Token mint the fee is paid in
+    pub payment_mint: Pubkey,
+    /// Whether the caller passes the prompt's access check, quotas and rate limit
+    pub access_granted: bool,
+    /// Whether the prompt and vault currently accept executions
+    pub executable: bool,
+}
+
 /// Parameters for recording an execution
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct RecordExecutionParams {
     /// Prompt ID
     pub prompt_id: String,
+    /// Execution ID, unique per prompt and caller (see `utils::generate_execution_id`)
+    pub execution_id: String,
     /// Version executed
     pub version: String,
     /// Hash of input data
     pub input_hash: [u8; 32],
     /// Hash of output data
     pub output_hash: [u8; 32],
-    /// Execution signature
+    /// Executor signing key that attested the execution
+    pub signing_key: Pubkey,
+    /// Executor's Ed25519 signature over `signing_message`
     pub signature: [u8; 64],
     /// Execution time in milliseconds
     pub execution_time_ms: u64,
+    /// Input tokens consumed, as attested by the executor
+    pub input_tokens: u64,
+    /// Output tokens produced, as attested by the executor
+    pub output_tokens: u64,
     /// Whether execution was successful
     pub success: bool,
     /// Error message if failed
     pub error_message: Option<String>,
+    /// Cause of the failure; required when `success` is false
+    pub failure_code: Option<FailureCode>,
+    /// Most the caller agrees to be charged; guards against price changes in flight
+    pub max_fee: u64,
+    /// Caller nonce the attestation is bound to
+    pub nonce: u64,
+    /// Last slot at which the attestation is accepted
+    pub expiry_slot: u64,
 }
 
 impl RecordExecutionParams {
@@ -110,6 +347,16 @@ impl RecordExecutionParams {
     pub fn validate(&self) -> Result<()> {
         require!(!self.prompt_id.is_empty(), crate::errors::VaultError::EmptyPromptId);
         require!(!self.version.is_empty(), crate::errors::VaultError::EmptyVersion);
+        require!(
+            !self.execution_id.is_empty() && self.execution_id.len() <= MAX_EXECUTION_ID_LEN,
+            crate::errors::VaultError::InvalidExecutionId
+        );
+        
+        // Failures must be classified, successes must not be
+        require!(
+            self.success == self.failure_code.is_none(),
+            crate::errors::VaultError::InvalidFailureCode
+        );
         
         // Validate error message length if present
         if let Some(ref error_msg) = self.error_message {
@@ -119,12 +366,201 @@ impl RecordExecutionParams {
         Ok(())
     }
 
-    /// Generate a unique seed for the execution record PDA
-    pub fn generate_execution_seed(&self, caller: &Pubkey) -> String {
-        format!("execution_{}_{}_{}_{}", 
-                self.prompt_id, 
-                caller.to_string()[..8].to_string(),
-                self.version,
-                Clock::get().unwrap().unix_timestamp)
+    /// Domain-separated preimage hash the executor signs
+    pub fn signing_message(&self, caller: &Pubkey) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(EXECUTION_SIGNATURE_DOMAIN);
+        hasher.update(crate::ID.as_ref());
+        // Length-prefix variable fields so the preimage is unambiguous
+        hasher.update((self.prompt_id.len() as u32).to_le_bytes());
+        hasher.update(self.prompt_id.as_bytes());
+        hasher.update((self.execution_id.len() as u32).to_le_bytes());
+        hasher.update(self.execution_id.as_bytes());
+        hasher.update(caller.as_ref());
+        hasher.update((self.version.len() as u32).to_le_bytes());
+        hasher.update(self.version.as_bytes());
+        hasher.update(self.input_hash);
+        hasher.update(self.output_hash);
+        hasher.update(self.execution_time_ms.to_le_bytes());
+        hasher.update(self.input_tokens.to_le_bytes());
+        hasher.update(self.output_tokens.to_le_bytes());
+        hasher.update([self.success as u8]);
+        hasher.update([self.failure_code.map_or(u8::MAX, |code| code as u8)]);
+        hasher.update(self.nonce.to_le_bytes());
+        hasher.update(self.expiry_slot.to_le_bytes());
+        
+        hasher.finalize().into()
+    }
+} 
+
+/// Parameters for a validator attesting an execution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttestExecutionParams {
+    /// Hash of input data the validator executed
+    pub input_hash: [u8; 32],
+    /// Hash of output data the validator obtained
+    pub output_hash: [u8; 32],
+    /// Validator signing key that attested the result
+    pub signing_key: Pubkey,
+    /// Validator's Ed25519 signature over `signing_message`
+    pub signature: [u8; 64],
+}
+
+impl AttestExecutionParams {
+    /// Domain-separated preimage hash the validator signs
+    pub fn signing_message(&self, execution_record: &Pubkey, validator: &Pubkey) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(ATTESTATION_SIGNATURE_DOMAIN);
+        hasher.update(crate::ID.as_ref());
+        hasher.update(execution_record.as_ref());
+        hasher.update(validator.as_ref());
+        hasher.update(self.input_hash);
+        hasher.update(self.output_hash);
+        
+        hasher.finalize().into()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_record() -> ExecutionRecord {
+        ExecutionRecord {
+            prompt_id: "prompt".to_string(),
+            execution_id: "execution".to_string(),
+            caller: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            executor: Pubkey::new_unique(),
+            version: "1.0.0".to_string(),
+            input_hash: [1; 32],
+            output_hash: [2; 32],
+            timestamp: 1000,
+            signature: [0; 64],
+            execution_time_ms: 100,
+            success: true,
+            error_message: None,
+            failure_code: None,
+            refunded_amount: 0,
+            status: ExecutionStatus::Finalized,
+            challenge_deadline: 1000,
+            fee_breakdown: FeeBreakdown {
+                total_fee: 1000,
+                creator_amount: 600,
+                dao_amount: 150,
+                validator_amount: 150,
+                burn_amount: 100,
+                integrator_amount: 0,
+            },
+            input_tokens: 0,
+            output_tokens: 0,
+            integrator: None,
+            discount_amount: 0,
+            sponsor_pool: None,
+            sponsored_amount: 0,
+            required_attestations: 0,
+            attestation_deadline: 0,
+            attesters: Vec::new(),
+            from_job: false,
+        }
+    }
+    
+    #[test]
+    fn test_challenge_window() {
+        let mut record = test_record();
+        assert!(!record.is_disputable(1000));
+        assert!(!record.is_finalizable(1000)); // settled at once without a window
+        
+        record.open_challenge_window(500, 1000);
+        assert!(record.is_disputable(1499));
+        assert!(!record.is_finalizable(1499));
+        assert!(!record.is_disputable(1500));
+        assert!(record.is_finalizable(1500));
+        
+        // A dispute holds the fee until it is resolved
+        record.status = ExecutionStatus::Disputed;
+        assert!(!record.is_disputable(1000));
+        assert!(!record.is_finalizable(2000));
+    }
+    
+    #[test]
+    fn test_quorum_attestation_reaches_finalization() {
+        let mut record = test_record();
+        record.required_attestations = 2;
+        record.await_quorum(600, 1000);
+        assert!(!record.is_attestation_overdue(1599));
+        assert!(!record.is_disputable(1000));
+        assert!(!record.is_finalizable(5000));
+        
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(!record.add_attester(first).unwrap());
+        assert!(record.add_attester(first).is_err()); // one attestation per validator
+        assert!(record.add_attester(second).unwrap());
+        
+        // Quorum opens the challenge window; the fee is released once it closes
+        record.open_challenge_window(500, 1200);
+        assert!(!record.is_attestation_overdue(1600));
+        assert!(record.is_disputable(1699));
+        assert!(record.is_finalizable(1700));
+    }
+    
+    #[test]
+    fn test_unattested_execution_is_refundable() {
+        let mut record = test_record();
+        record.required_attestations = 2;
+        record.await_quorum(600, 1000);
+        assert!(!record.add_attester(Pubkey::new_unique()).unwrap());
+        assert!(!record.is_attestation_overdue(1599));
+        assert!(record.is_attestation_overdue(1600));
+        
+        // Attesters are capped at what the account has room for
+        for _ in 1..MAX_EXECUTION_ATTESTERS {
+            record.add_attester(Pubkey::new_unique()).unwrap();
+        }
+        assert!(record.add_attester(Pubkey::new_unique()).is_err());
+    }
+    
+    #[test]
+    fn test_attestation_message_is_bound_to_record_and_validator() {
+        let params = AttestExecutionParams {
+            input_hash: [1; 32],
+            output_hash: [2; 32],
+            signing_key: Pubkey::new_unique(),
+            signature: [0; 64],
+        };
+        let record = Pubkey::new_unique();
+        let validator = Pubkey::new_unique();
+        let message = params.signing_message(&record, &validator);
+        
+        // A signature cannot be replayed against another record or by another validator
+        assert_ne!(message, params.signing_message(&Pubkey::new_unique(), &validator));
+        assert_ne!(message, params.signing_message(&record, &Pubkey::new_unique()));
+        let mut other_output = params.clone();
+        other_output.output_hash = [3; 32];
+        assert_ne!(message, other_output.signing_message(&record, &validator));
+    }
+    
+    #[test]
+    fn test_sponsored_refund_split() {
+        let mut record = test_record();
+        assert_eq!(record.caller_refund_amount(), 1000);
+        
+        // A sponsored fee goes back to the pool; the caller only gets what they paid
+        record.sponsor_pool = Some(Pubkey::new_unique());
+        record.sponsored_amount = 1000;
+        assert_eq!(record.caller_refund_amount(), 0);
+        record.sponsored_amount = 400;
+        assert_eq!(record.caller_refund_amount(), 600);
+        
+        let mut usage = SponsorUsage {
+            pool: record.sponsor_pool.unwrap(),
+            user: record.caller,
+            amount_sponsored: 0,
+            executions_sponsored: 0,
+        };
+        usage.record_sponsorship(400);
+        usage.credit_refund(record.sponsored_amount);
+        assert_eq!(usage.amount_sponsored, 0);
+        assert_eq!(usage.executions_sponsored, 1);
+    }
+}
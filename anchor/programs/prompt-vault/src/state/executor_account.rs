@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Role an identity plays in attesting executions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutorRole {
+    /// Runs prompts and attests their results
+    #[default]
+    Executor = 0,
+    /// Independently validates executions
+    Validator = 1,
+    /// Both executes and validates
+    Both = 2,
+}
+
+/// Executor registry status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExecutorStatus {
+    /// Allowed to attest
+    #[default]
+    Active = 0,
+    /// Suspended by the admin
+    Jailed = 1,
+    /// Unbonding after requesting exit
+    Exiting = 2,
+}
+
+/// Registered executor or validator identity with a posted bond
+#[account]
+pub struct ExecutorAccount {
+    /// Identity that registered and controls the entry
+    pub authority: Pubkey,
+    /// Role in attesting executions
+    pub role: ExecutorRole,
+    /// Current registry status
+    pub status: ExecutorStatus,
+    /// Ed25519 keys whose signatures count as this identity's attestations
+    pub signing_keys: Vec<Pubkey>,
+    /// URI describing the operator (endpoints, models, contact)
+    pub metadata_uri: String,
+    /// Tokens bonded in the executor bond pool
+    pub bond_amount: u64,
+    /// Number of executions attested
+    pub executions_attested: u64,
+    /// Validator rewards credited but not yet claimed
+    pub pending_rewards: u64,
+    /// Executions run or attested whose fee is still escrowed awaiting settlement
+    pub open_settlements: u32,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// When exit was requested (0 if not exiting)
+    pub exit_requested_at: i64,
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl ExecutorAccount {
+    pub const SPACE: usize = EXECUTOR_ACCOUNT_SIZE;
+
+    /// Check if the identity is active
+    pub fn is_active(&self) -> bool {
+        matches!(self.status, ExecutorStatus::Active)
+    }
+
+    /// Check if the identity may attest executions
+    pub fn can_execute(&self) -> bool {
+        self.is_active() && matches!(self.role, ExecutorRole::Executor | ExecutorRole::Both)
+    }
+
+    /// Check if the identity may validate executions
+    pub fn can_validate(&self) -> bool {
+        self.is_active() && matches!(self.role, ExecutorRole::Validator | ExecutorRole::Both)
+    }
+
+    /// Check if a key is one of the identity's signing keys
+    pub fn has_signing_key(&self, key: &Pubkey) -> bool {
+        self.signing_keys.contains(key)
+    }
+
+    /// Check if the unbonding period has elapsed since exit was requested
+    pub fn is_unbonded(&self, unbonding_period: i64) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp;
+        matches!(self.status, ExecutorStatus::Exiting) &&
+            current_time.saturating_sub(self.exit_requested_at) >= unbonding_period
+    }
+
+    /// Track an executed or attested execution whose fee was escrowed
+    pub fn open_settlement(&mut self) {
+        self.open_settlements = self.open_settlements.saturating_add(1);
+    }
+
+    /// Release an escrowed execution once it is finalized or reversed
+    pub fn close_settlement(&mut self) {
+        self.open_settlements = self.open_settlements.saturating_sub(1);
+    }
+
+    /// Slash up to `amount` of the bond, jailing an active identity left below `min_bond`
+    ///
+    /// Returns the amount slashed.
+    pub fn slash_bond(&mut self, amount: u64, min_bond: u64) -> u64 {
+        let slashed = amount.min(self.bond_amount);
+        self.bond_amount -= slashed;
+        if self.bond_amount < min_bond && self.status == ExecutorStatus::Active {
+            self.status = ExecutorStatus::Jailed;
+        }
+        slashed
+    }
+
+    /// Update the last modified timestamp
+    pub fn touch(&mut self) {
+        self.last_updated = Clock::get().unwrap().unix_timestamp;
+    }
+}
+
+/// Validate executor signing keys and metadata
+fn validate_executor_profile(signing_keys: &[Pubkey], metadata_uri: &str) -> Result<()> {
+    require!(!signing_keys.is_empty(), crate::errors::VaultError::UnauthorizedExecutor);
+    require!(signing_keys.len() <= 4, crate::errors::VaultError::TooManySigningKeys);
+    require!(metadata_uri.len() <= 256, crate::errors::VaultError::MetadataUriTooLong);
+    Ok(())
+}
+
+/// Parameters for registering an executor or validator
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterExecutorParams {
+    /// Role in attesting executions
+    pub role: ExecutorRole,
+    /// Ed25519 signing keys
+    pub signing_keys: Vec<Pubkey>,
+    /// Operator metadata URI
+    pub metadata_uri: String,
+    /// Bond to post
+    pub bond_amount: u64,
+}
+
+impl RegisterExecutorParams {
+    /// Validate the registration parameters
+    pub fn validate(&self, min_bond: u64) -> Result<()> {
+        validate_executor_profile(&self.signing_keys, &self.metadata_uri)?;
+        require!(self.bond_amount >= min_bond, crate::errors::VaultError::ExecutorBondBelowMinimum);
+        Ok(())
+    }
+}
+
+/// Parameters for updating an executor's profile
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateExecutorParams {
+    /// New signing keys
+    pub signing_keys: Option<Vec<Pubkey>>,
+    /// New metadata URI
+    pub metadata_uri: Option<String>,
+    /// Additional bond to post
+    pub additional_bond: u64,
+}
+
+impl UpdateExecutorParams {
+    /// Validate the profile `executor` would have after the update
+    pub fn validate(&self, executor: &ExecutorAccount) -> Result<()> {
+        validate_executor_profile(
+            self.signing_keys.as_deref().unwrap_or(&executor.signing_keys),
+            self.metadata_uri.as_deref().unwrap_or(&executor.metadata_uri),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_executor(role: ExecutorRole, bond_amount: u64) -> ExecutorAccount {
+        ExecutorAccount {
+            authority: Pubkey::new_unique(),
+            role,
+            status: ExecutorStatus::Active,
+            signing_keys: vec![Pubkey::new_unique()],
+            metadata_uri: String::new(),
+            bond_amount,
+            executions_attested: 0,
+            pending_rewards: 0,
+            open_settlements: 0,
+            registered_at: 0,
+            exit_requested_at: 0,
+            last_updated: 0,
+        }
+    }
+    
+    #[test]
+    fn test_slash_bond() {
+        let mut executor = test_executor(ExecutorRole::Both, 1500);
+        assert_eq!(executor.slash_bond(400, 1000), 400);
+        assert!(executor.status == ExecutorStatus::Active);
+        
+        // Falling below the minimum bond jails the executor
+        assert_eq!(executor.slash_bond(400, 1000), 400);
+        assert_eq!(executor.bond_amount, 700);
+        assert!(executor.status == ExecutorStatus::Jailed);
+        assert!(!executor.can_validate());
+        
+        // Slashing is capped at what is left
+        assert_eq!(executor.slash_bond(1000, 1000), 700);
+        assert_eq!(executor.bond_amount, 0);
+        
+        // Exiting executors keep their status
+        let mut exiting = test_executor(ExecutorRole::Executor, 1500);
+        exiting.status = ExecutorStatus::Exiting;
+        exiting.slash_bond(1000, 1000);
+        assert!(exiting.status == ExecutorStatus::Exiting);
+    }
+}
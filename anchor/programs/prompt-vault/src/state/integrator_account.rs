@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Frontend or agent platform that routes executions for a share of their fees
+#[account]
+pub struct IntegratorAccount {
+    /// Wallet that registered the integrator and receives its fees
+    pub authority: Pubkey,
+    /// Requested share of each routed execution's fee (basis points)
+    pub fee_bps: u16,
+    /// Executions routed through the integrator
+    pub total_executions: u64,
+    /// Fees paid out to the integrator
+    pub total_fees_earned: u64,
+    /// Registration timestamp
+    pub created_at: i64,
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl IntegratorAccount {
+    pub const SPACE: usize = INTEGRATOR_ACCOUNT_SIZE;
+
+    /// Share actually applied, capped by the vault's integrator configuration
+    pub fn effective_fee_bps(&self, config: &IntegratorConfig) -> u16 {
+        self.fee_bps.min(config.max_fee_bps)
+    }
+
+    /// Count an execution routed through the integrator
+    pub fn record_execution(&mut self) {
+        self.total_executions = self.total_executions.saturating_add(1);
+        self.touch();
+    }
+
+    /// Add a paid-out fee to the integrator's totals
+    pub fn record_fee(&mut self, amount: u64) {
+        self.total_fees_earned = self.total_fees_earned.saturating_add(amount);
+        self.touch();
+    }
+
+    /// Update the last modified timestamp
+    pub fn touch(&mut self) {
+        self.last_updated = Clock::get().unwrap().unix_timestamp;
+    }
+}
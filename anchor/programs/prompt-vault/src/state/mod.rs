@@ -4,16 +4,53 @@ pub mod vault_state;
 pub mod prompt_data;
 pub mod execution_record;
 pub mod stake_account;
+pub mod slash_proposal;
+pub mod executor_account;
+pub mod dispute;
+pub mod execution_nonce;
+pub mod user_usage;
+pub mod prompt_analytics;
+pub mod execution_job;
+pub mod integrator_account;
+pub mod sponsor_pool;
 
 pub use vault_state::*;
 pub use prompt_data::*;
 pub use execution_record::*;
 pub use stake_account::*;
+pub use slash_proposal::*;
+pub use executor_account::*;
+pub use dispute::*;
+pub use execution_nonce::*;
+pub use user_usage::*;
+pub use prompt_analytics::*;
+pub use execution_job::*;
+pub use integrator_account::*;
+pub use sponsor_pool::*;
+
+/// Precision of the cumulative slash factor (1_000_000_000 = nothing slashed)
+pub const SLASH_FACTOR_PRECISION: u64 = 1_000_000_000;
+
+/// Largest share of a prompt's stake a single slash may take, in basis points
+///
+/// A full slash would drive the slash factor to zero, which stake accounts
+/// read as "no snapshot", so some stake always survives a slash.
+pub const MAX_SLASH_BPS: u16 = 9900;
+
+/// Length of a usage-quota day in seconds
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Longest grace period between an emergency pause and emergency withdrawals (seconds)
+pub const MAX_EMERGENCY_WITHDRAW_GRACE: i64 = 7 * SECONDS_PER_DAY;
+
+/// Longest dispute window a slash proposal may wait out (seconds)
+pub const MAX_SLASH_DISPUTE_WINDOW: i64 = 30 * SECONDS_PER_DAY;
 
 /// License types for prompts
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum LicenseType {
     /// Free to use by anyone
+    #[default]
     Public = 0,
     /// Requires token payment per use
     TokenGated = 1,
@@ -25,16 +62,11 @@ pub enum LicenseType {
     Custom = 4,
 }
 
-impl Default for LicenseType {
-    fn default() -> Self {
-        LicenseType::Public
-    }
-}
-
 /// Prompt status enumeration
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum PromptStatus {
     /// Draft state, not yet published
+    #[default]
     Draft = 0,
     /// Active and available for use
     Active = 1,
@@ -46,9 +78,127 @@ pub enum PromptStatus {
     Removed = 4,
 }
 
-impl Default for PromptStatus {
-    fn default() -> Self {
-        PromptStatus::Draft
+/// How much of the fee is returned to the caller when an execution fails
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RefundPolicy {
+    /// Failed executions are charged in full
+    #[default]
+    None,
+    /// A share of the fee is refunded, in basis points
+    Partial { refund_bps: u16 },
+    /// Failed executions are free
+    Full,
+}
+
+impl RefundPolicy {
+    /// Validate the refund share
+    pub fn validate(&self) -> Result<()> {
+        if let RefundPolicy::Partial { refund_bps } = self {
+            require!(*refund_bps <= 10000, crate::errors::VaultError::InvalidRefundPolicy);
+        }
+        Ok(())
+    }
+
+    /// Share of the fee refunded for a failed execution, in basis points
+    pub fn refund_bps(&self) -> u16 {
+        match self {
+            RefundPolicy::None => 0,
+            RefundPolicy::Partial { refund_bps } => *refund_bps,
+            RefundPolicy::Full => 10000,
+        }
+    }
+
+    /// Amount of `fee` refunded for a failed execution
+    pub fn refund_amount(&self, fee: u64) -> u64 {
+        match self {
+            RefundPolicy::None => 0,
+            RefundPolicy::Partial { refund_bps } => crate::utils::calculate_bps_amount(fee, *refund_bps),
+            RefundPolicy::Full => fee,
+        }
+    }
+}
+
+/// Token-metered pricing charged on top of a prompt's base `fee_amount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct MeteredPricing {
+    /// Price per 1,000 input tokens
+    pub price_per_1k_input_tokens: u64,
+    /// Price per 1,000 output tokens
+    pub price_per_1k_output_tokens: u64,
+    /// Cap on the total charge per execution (None = uncapped)
+    pub max_fee: Option<u64>,
+}
+
+impl MeteredPricing {
+    /// Validate the pricing
+    pub fn validate(&self) -> Result<()> {
+        require!(self.max_fee != Some(0), crate::errors::VaultError::InvalidMeteredPricing);
+        Ok(())
+    }
+
+    /// Check if the price depends on token counts
+    pub fn is_metered(&self) -> bool {
+        self.price_per_1k_input_tokens > 0 || self.price_per_1k_output_tokens > 0
+    }
+
+    /// Cheaper of the two pricings, taken term by term
+    pub fn lower_of(&self, other: &MeteredPricing) -> MeteredPricing {
+        let max_fee = match (self.max_fee, other.max_fee) {
+            (Some(current), Some(new)) => Some(current.min(new)),
+            (Some(cap), None) | (None, Some(cap)) => Some(cap),
+            (None, None) => None,
+        };
+        
+        MeteredPricing {
+            price_per_1k_input_tokens: self.price_per_1k_input_tokens.min(other.price_per_1k_input_tokens),
+            price_per_1k_output_tokens: self.price_per_1k_output_tokens.min(other.price_per_1k_output_tokens),
+            max_fee,
+        }
+    }
+}
+
+/// Terms that determine what a caller pays for an execution
+#[derive(Clone, Copy)]
+pub struct PriceTerms {
+    /// Base fee
+    pub fee_amount: u64,
+    /// Per-token pricing
+    pub metered_pricing: MeteredPricing,
+    /// Fee split
+    pub royalty_config: RoyaltyConfig,
+    /// Refund applied to failed executions
+    pub refund_policy: RefundPolicy,
+    /// Validator attestations required before the fee is released
+    pub validator_quorum: u8,
+}
+
+/// Price increase, refund cut or split or quorum change waiting out the vault's notice period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PendingPriceChange {
+    /// Base fee once the change takes effect
+    pub fee_amount: u64,
+    /// Per-token pricing once the change takes effect
+    pub metered_pricing: MeteredPricing,
+    /// Fee split once the change takes effect
+    pub royalty_config: RoyaltyConfig,
+    /// Refund policy once the change takes effect
+    pub refund_policy: RefundPolicy,
+    /// Validator quorum once the change takes effect
+    pub validator_quorum: u8,
+    /// When the change takes effect
+    pub effective_at: i64,
+}
+
+impl PendingPriceChange {
+    /// Terms in effect once the change applies
+    pub fn terms(&self) -> PriceTerms {
+        PriceTerms {
+            fee_amount: self.fee_amount,
+            metered_pricing: self.metered_pricing,
+            royalty_config: self.royalty_config,
+            refund_policy: self.refund_policy,
+            validator_quorum: self.validator_quorum,
+        }
     }
 }
 
@@ -65,23 +215,71 @@ pub struct VersionEntry {
     pub content_hash: [u8; 32],
 }
 
+/// Upper bounds (inclusive, milliseconds) of the latency histogram buckets;
+/// the final bucket counts everything slower than the last bound
+pub const LATENCY_BUCKET_BOUNDS_MS: [u64; LATENCY_HISTOGRAM_BUCKETS - 1] =
+    [50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 30_000, 60_000, 120_000];
+
+/// Number of latency histogram buckets
+pub const LATENCY_HISTOGRAM_BUCKETS: usize = 12;
+
 /// Execution statistics
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct ExecutionStats {
     /// Total number of executions
     pub total_executions: u64,
-    /// Total revenue generated
+    /// Total revenue from successful executions, counted once their fees are distributed
     pub total_revenue: u64,
-    /// Average execution time (milliseconds)
+    /// Average execution time (milliseconds), derived from `total_latency_ms`
     pub avg_execution_time: u32,
-    /// Success rate (basis points, 10000 = 100%)
+    /// Success rate (basis points, 10000 = 100%), derived from the exact counters
     pub success_rate: u16,
     /// Last execution timestamp
     pub last_execution: i64,
+    /// Successful executions
+    pub successful_executions: u64,
+    /// Failed executions
+    pub failed_executions: u64,
+    /// Sum of all execution times in milliseconds
+    pub total_latency_ms: u128,
+    /// Execution counts per `LATENCY_BUCKET_BOUNDS_MS` bucket
+    pub latency_histogram: [u64; LATENCY_HISTOGRAM_BUCKETS],
+    /// Failed executions per `FailureCode`, indexed by discriminant
+    pub failures_by_code: [u64; FAILURE_CODE_COUNT],
+}
+
+impl ExecutionStats {
+    /// Add an execution to the counters and histogram
+    pub fn record(&mut self, execution_time_ms: u64, success: bool, failure_code: Option<FailureCode>) {
+        self.total_executions = self.total_executions.saturating_add(1);
+        if success {
+            self.successful_executions = self.successful_executions.saturating_add(1);
+        } else {
+            self.failed_executions = self.failed_executions.saturating_add(1);
+        }
+        if let Some(code) = failure_code {
+            let count = &mut self.failures_by_code[code as usize];
+            *count = count.saturating_add(1);
+        }
+        self.total_latency_ms = self.total_latency_ms.saturating_add(execution_time_ms as u128);
+        
+        let bucket = crate::utils::latency_bucket_index(execution_time_ms);
+        self.latency_histogram[bucket] = self.latency_histogram[bucket].saturating_add(1);
+        
+        // Keep the summary fields exact rather than incrementally rounded
+        let counted = (self.successful_executions + self.failed_executions).max(1);
+        self.avg_execution_time = (self.total_latency_ms / counted as u128).min(u32::MAX as u128) as u32;
+        self.success_rate = ((self.successful_executions as u128 * 10000) / counted as u128) as u16;
+    }
+
+    /// Latency at `percentile_bps` (e.g. 9500 for p95), as the bound of its histogram bucket
+    pub fn latency_percentile(&self, percentile_bps: u16) -> Option<u64> {
+        crate::utils::histogram_percentile(&self.latency_histogram, percentile_bps)
+    }
 }
 
 /// Royalty configuration
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub struct RoyaltyConfig {
     /// Creator share in basis points (10000 = 100%)
     pub creator_share_bps: u16,
@@ -104,6 +302,53 @@ impl Default for RoyaltyConfig {
     }
 }
 
+/// How an execution fee is split between recipients
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FeeBreakdown {
+    /// Total fee charged
+    pub total_fee: u64,
+    /// Creator share
+    pub creator_amount: u64,
+    /// DAO treasury share
+    pub dao_amount: u64,
+    /// Validator pool share
+    pub validator_amount: u64,
+    /// Burned share
+    pub burn_amount: u64,
+    /// Share paid to the integrator that routed the execution
+    pub integrator_amount: u64,
+}
+
+impl FeeBreakdown {
+    /// Fee the prompt earned, net of the integrator's share
+    pub fn revenue(&self) -> u64 {
+        self.total_fee.saturating_sub(self.integrator_amount)
+    }
+
+    /// Drop the creator's share from the fee, so the caller is not charged for it
+    pub fn waive_creator_share(&mut self) {
+        self.total_fee = self.total_fee.saturating_sub(self.creator_amount);
+        self.creator_amount = 0;
+    }
+
+    /// Add an integrator's share of the fee, taken from the protocol's share
+    /// or charged on top depending on `source`
+    pub fn add_integrator_share(&mut self, integrator_bps: u16, source: IntegratorFeeSource) {
+        let share = crate::utils::calculate_bps_amount(self.total_fee, integrator_bps);
+        match source {
+            IntegratorFeeSource::ProtocolShare => {
+                let share = share.min(self.dao_amount);
+                self.dao_amount -= share;
+                self.integrator_amount = share;
+            }
+            IntegratorFeeSource::OnTop => {
+                self.total_fee = self.total_fee.saturating_add(share);
+                self.integrator_amount = share;
+            }
+        }
+    }
+}
+
 /// Tag for categorizing prompts
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct PromptTag {
@@ -122,10 +367,462 @@ pub struct AccessControl {
     pub whitelist: Vec<Pubkey>,
     /// Maximum uses per user per day
     pub daily_usage_limit: Option<u32>,
+    /// Maximum executions of the prompt across all users (limited editions)
+    pub max_total_executions: Option<u64>,
+    /// Maximum executions of the prompt across all users per slot window
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// Prompt-wide execution rate limit over a sliding window of slots
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RateLimit {
+    /// Executions allowed within any window
+    pub max_executions: u32,
+    /// Window length in slots
+    pub window_slots: u64,
+}
+
+/// Counters enforcing a prompt's execution cap and rate limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ExecutionThrottle {
+    /// Executions admitted all-time, including jobs not yet settled
+    pub admitted_executions: u64,
+    /// First slot of the current rate limit window
+    pub window_start_slot: u64,
+    /// Executions admitted in the current window
+    pub current_window_count: u32,
+    /// Executions admitted in the previous window
+    pub previous_window_count: u32,
+}
+
+impl ExecutionThrottle {
+    /// Executions left under the prompt's cap (None if uncapped)
+    pub fn remaining_total(&self, access_control: &AccessControl) -> Option<u64> {
+        access_control.max_total_executions
+            .map(|cap| cap.saturating_sub(self.admitted_executions))
+    }
+
+    /// Check if the rate limit leaves no room for another execution at `slot`
+    pub fn is_rate_limited(&self, access_control: &AccessControl, slot: u64) -> bool {
+        let Some(rate_limit) = access_control.rate_limit else {
+            return false;
+        };
+        let (start, current, previous) = crate::utils::roll_execution_window(
+            self.window_start_slot,
+            self.current_window_count,
+            self.previous_window_count,
+            rate_limit.window_slots,
+            slot,
+        );
+        crate::utils::sliding_window_count(start, current, previous, rate_limit.window_slots, slot)
+            >= rate_limit.max_executions as u64
+    }
+
+    /// Count an execution, enforcing the cap and rate limit
+    pub fn admit(&mut self, access_control: &AccessControl, slot: u64) -> Result<()> {
+        require!(
+            self.remaining_total(access_control) != Some(0),
+            crate::errors::VaultError::MaxExecutionLimitReached
+        );
+        require!(
+            !self.is_rate_limited(access_control, slot),
+            crate::errors::VaultError::RateLimitExceeded
+        );
+        
+        if let Some(rate_limit) = access_control.rate_limit {
+            let (start, current, previous) = crate::utils::roll_execution_window(
+                self.window_start_slot,
+                self.current_window_count,
+                self.previous_window_count,
+                rate_limit.window_slots,
+                slot,
+            );
+            self.window_start_slot = start;
+            self.current_window_count = current.saturating_add(1);
+            self.previous_window_count = previous;
+        }
+        self.admitted_executions = self.admitted_executions.saturating_add(1);
+        Ok(())
+    }
+
+    /// Give back the cap slot of an admitted execution that never ran
+    pub fn release(&mut self) {
+        self.admitted_executions = self.admitted_executions.saturating_sub(1);
+    }
+}
+
+/// Staking economics configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakingConfig {
+    /// Minimum amount a stake position may hold
+    pub min_stake_amount: u64,
+    /// Maximum amount a stake position may hold
+    pub max_stake_amount: u64,
+    /// Minimum time staked before rewards can be claimed (seconds)
+    pub min_stake_duration: i64,
+    /// Reward emission per staked token per second (scaled by 1_000_000)
+    pub reward_rate_per_second: u64,
+    /// Timestamp from which rewards accrue
+    pub reward_start_time: i64,
+    /// Timestamp after which rewards stop accruing (0 = no end)
+    pub reward_end_time: i64,
+    /// Time after the latest deposit before principal can be unstaked (seconds)
+    pub unstake_cooldown: i64,
+    /// Time after an emergency pause before stakers may emergency withdraw (seconds).
+    /// A pending slash also holds positions for this long past its dispute window.
+    pub emergency_withdraw_grace: i64,
+}
+
+impl Default for StakingConfig {
+    fn default() -> Self {
+        Self {
+            min_stake_amount: 1,
+            max_stake_amount: u64::MAX,
+            min_stake_duration: 86400, // 24 hours
+            reward_rate_per_second: 100,
+            reward_start_time: 0,
+            reward_end_time: 0,
+            unstake_cooldown: 0,
+            emergency_withdraw_grace: 0,
+        }
+    }
+}
+
+impl StakingConfig {
+    /// Validate the staking configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_stake_amount > 0 && self.max_stake_amount >= self.min_stake_amount,
+            crate::errors::VaultError::InvalidStakingConfig
+        );
+        require!(
+            self.min_stake_duration >= 0 &&
+                self.unstake_cooldown >= 0 &&
+                self.emergency_withdraw_grace >= 0,
+            crate::errors::VaultError::InvalidStakingConfig
+        );
+        require!(
+            self.emergency_withdraw_grace <= MAX_EMERGENCY_WITHDRAW_GRACE,
+            crate::errors::VaultError::InvalidStakingConfig
+        );
+        require!(
+            self.reward_end_time == 0 || self.reward_end_time > self.reward_start_time,
+            crate::errors::VaultError::InvalidStakingConfig
+        );
+        Ok(())
+    }
+
+    /// Check that a position balance is within the configured bounds
+    pub fn check_stake_amount(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_stake_amount, crate::errors::VaultError::StakeAmountBelowMinimum);
+        require!(amount <= self.max_stake_amount, crate::errors::VaultError::StakeAmountAboveMaximum);
+        Ok(())
+    }
+
+    /// Number of seconds between `from` and `to` that fall inside the emission schedule
+    pub fn emission_seconds(&self, from: i64, to: i64) -> u64 {
+        let start = from.max(self.reward_start_time);
+        let end = if self.reward_end_time > 0 {
+            to.min(self.reward_end_time)
+        } else {
+            to
+        };
+
+        if end > start {
+            (end - start) as u64
+        } else {
+            0
+        }
+    }
+}
+
+/// Where slashed stake is sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlashDestination {
+    /// Transferred to the protocol treasury
+    #[default]
+    Treasury = 0,
+    /// Burned from the stake pool
+    Burn = 1,
+}
+
+/// Stake slashing configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SlashingConfig {
+    /// Share of the stake on a prompt slashed per proposal, in basis points (at most MAX_SLASH_BPS)
+    pub slash_bps: u16,
+    /// Where slashed stake is sent
+    pub destination: SlashDestination,
+    /// Time between proposal and earliest execution (seconds)
+    pub dispute_window: i64,
+    /// Governance authority allowed to propose and cancel slashes alongside the admin
+    pub governance: Option<Pubkey>,
+}
+
+impl Default for SlashingConfig {
+    fn default() -> Self {
+        Self {
+            slash_bps: 1000, // 10%
+            destination: SlashDestination::Treasury,
+            dispute_window: 259200, // 3 days
+            governance: None,
+        }
+    }
+}
+
+impl SlashingConfig {
+    /// Validate the slashing configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.slash_bps > 0 && self.slash_bps <= MAX_SLASH_BPS,
+            crate::errors::VaultError::InvalidSlashingConfig
+        );
+        require!(
+            self.dispute_window >= 0 && self.dispute_window <= MAX_SLASH_DISPUTE_WINDOW,
+            crate::errors::VaultError::InvalidSlashingConfig
+        );
+        Ok(())
+    }
+}
+
+/// Executor registry configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegistryConfig {
+    /// Minimum bond an executor or validator must post
+    pub min_executor_bond: u64,
+    /// Time between requesting exit and withdrawing the bond (seconds)
+    pub unbonding_period: i64,
+    /// Time validators have to reach a prompt's quorum before the fee is refundable (seconds)
+    pub attestation_window: i64,
+}
+
+impl Default for RegistryConfig {
+    fn default() -> Self {
+        Self {
+            min_executor_bond: 1,
+            unbonding_period: 604800, // 7 days
+            attestation_window: 86400, // 1 day
+        }
+    }
+}
+
+impl RegistryConfig {
+    /// Validate the registry configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(self.min_executor_bond > 0, crate::errors::VaultError::InvalidRegistryConfig);
+        require!(self.unbonding_period >= 0, crate::errors::VaultError::InvalidRegistryConfig);
+        require!(self.attestation_window > 0, crate::errors::VaultError::InvalidRegistryConfig);
+        Ok(())
+    }
 }
 
+/// Optimistic execution dispute configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DisputeConfig {
+    /// Time after recording during which an execution can be disputed (seconds, 0 = disabled)
+    pub challenge_window: i64,
+    /// Bond a challenger posts, and the amount slashed from the losing side
+    pub dispute_bond: u64,
+    /// Validator votes needed to resolve a dispute (0 = arbiter only)
+    pub validator_quorum: u8,
+    /// Key that may resolve disputes besides the admin (None = admin only)
+    pub arbiter: Option<Pubkey>,
+    /// Time a dispute has to be resolved before anyone can resolve it as rejected (seconds)
+    pub dispute_timeout: i64,
+}
+
+impl Default for DisputeConfig {
+    fn default() -> Self {
+        Self {
+            challenge_window: 0,
+            dispute_bond: 0,
+            validator_quorum: 0,
+            arbiter: None,
+            dispute_timeout: 604800, // 7 days
+        }
+    }
+}
+
+impl DisputeConfig {
+    /// Validate the dispute configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(self.challenge_window >= 0, crate::errors::VaultError::InvalidDisputeConfig);
+        require!(
+            self.challenge_window == 0 || self.dispute_bond > 0,
+            crate::errors::VaultError::InvalidDisputeConfig
+        );
+        require!(
+            self.validator_quorum as usize <= MAX_DISPUTE_VOTERS,
+            crate::errors::VaultError::InvalidDisputeConfig
+        );
+        require!(self.dispute_timeout > 0, crate::errors::VaultError::InvalidDisputeConfig);
+        Ok(())
+    }
+}
+
+/// Execution job queue configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct JobConfig {
+    /// Time an open job has to be claimed and fulfilled before it can be refunded (seconds)
+    pub job_timeout: i64,
+    /// Time a caller has to accept a fulfilled job before it finalizes on its own (seconds)
+    pub acceptance_window: i64,
+}
+
+impl Default for JobConfig {
+    fn default() -> Self {
+        Self {
+            job_timeout: 3600, // 1 hour
+            acceptance_window: 3600, // 1 hour
+        }
+    }
+}
+
+impl JobConfig {
+    /// Validate the job configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(self.job_timeout > 0, crate::errors::VaultError::InvalidJobConfig);
+        require!(self.acceptance_window >= 0, crate::errors::VaultError::InvalidJobConfig);
+        Ok(())
+    }
+}
+
+/// Where an integrator's share of an execution fee comes from
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegratorFeeSource {
+    /// Taken out of the DAO treasury's share
+    #[default]
+    ProtocolShare = 0,
+    /// Charged to the caller on top of the prompt fee
+    OnTop = 1,
+}
+
+/// Referral and integrator fee configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IntegratorConfig {
+    /// Cap on an integrator's share of an execution fee (basis points)
+    pub max_fee_bps: u16,
+    /// Where the integrator's share comes from
+    pub fee_source: IntegratorFeeSource,
+}
+
+impl Default for IntegratorConfig {
+    fn default() -> Self {
+        Self {
+            max_fee_bps: 500, // 5%
+            fee_source: IntegratorFeeSource::ProtocolShare,
+        }
+    }
+}
+
+impl IntegratorConfig {
+    /// Validate the integrator configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(self.max_fee_bps <= 10000, crate::errors::VaultError::InvalidIntegratorFee);
+        Ok(())
+    }
+}
+
+/// What a fee discount tier is keyed on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DiscountBasis {
+    /// Caller's effective stake in one position on the executed prompt (positions are not summed)
+    Staked = 0,
+    /// Caller's payment token balance
+    Held = 1,
+}
+
+/// Fee discount granted once the caller reaches a staked or held amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct DiscountTier {
+    /// Amount the tier is keyed on
+    pub basis: DiscountBasis,
+    /// Minimum staked or held amount to qualify
+    pub min_amount: u64,
+    /// Discount off the execution fee (basis points)
+    pub discount_bps: u16,
+}
+
+/// Holder and staker fee discount configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct DiscountConfig {
+    /// Discount tiers; the best tier the caller qualifies for applies
+    pub tiers: Vec<DiscountTier>,
+}
+
+impl DiscountConfig {
+    /// Validate the discount configuration
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.tiers.len() <= MAX_DISCOUNT_TIERS &&
+                self.tiers.iter().all(|tier| tier.discount_bps <= 10000),
+            crate::errors::VaultError::InvalidDiscountConfig
+        );
+        Ok(())
+    }
+
+    /// Best discount for a caller with the given stake and token balance
+    pub fn discount_bps(&self, staked_amount: u64, held_amount: u64) -> u16 {
+        self.tiers
+            .iter()
+            .filter(|tier| match tier.basis {
+                DiscountBasis::Staked => staked_amount >= tier.min_amount,
+                DiscountBasis::Held => held_amount >= tier.min_amount,
+            })
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Maximum number of fee discount tiers in the vault config
+pub const MAX_DISCOUNT_TIERS: usize = 8;
+
+/// Maximum users on a sponsor pool's allowlist
+pub const MAX_SPONSOR_ALLOWLIST: usize = 32;
+
+/// Maximum execution ID length; bounded by the 32-byte PDA seed limit
+pub const MAX_EXECUTION_ID_LEN: usize = 32;
+
+/// Maximum validator attestations a prompt can require per execution
+pub const MAX_EXECUTION_ATTESTERS: usize = 8;
+
+/// Days kept in the daily analytics ring buffer
+pub const ANALYTICS_DAILY_BUCKETS: usize = 30;
+
+/// Weeks kept in the weekly analytics ring buffer
+pub const ANALYTICS_WEEKLY_BUCKETS: usize = 12;
+
+/// Maximum number of validator votes tracked on a dispute
+pub const MAX_DISPUTE_VOTERS: usize = 16;
+
 /// Constants for account sizes
 pub const VAULT_STATE_SIZE: usize = 8 + // discriminator
+    32 + // admin
+    32 + // treasury
+    32 + // payment_mint
+    8 + // prompt_count
+    2 + // protocol_fee_bps
+    2 + // creator_share_bps
+    2 + // validator_share_bps
+    1 + // is_paused
+    8 + // created_at
+    8 + // last_updated
+    8 + // paused_at
+    (8 + 8 + 8 + 8 + 8 + 8 + 8 + 8) + // staking_config
+    (2 + 1 + 8 + 33) + // slashing_config
+    8 + // execution_retention_period
+    (8 + 8 + 8) + // registry_config
+    (8 + 8 + 1 + 33 + 8) + // dispute_config
+    (8 + 8) + // job_config
+    8 + // price_change_notice_period
+    (2 + 1) + // integrator_config
+    (4 + (1 + 8 + 2) * MAX_DISCOUNT_TIERS) + // discount_config
+    4 + // active_validators
+    64; // padding
+
+/// Size of vault state accounts created before the configurable economics
+pub const LEGACY_VAULT_STATE_SIZE: usize = 8 + // discriminator
     32 + // admin
     32 + // treasury
     8 + // prompt_count
@@ -151,15 +848,27 @@ pub const PROMPT_DATA_SIZE: usize = 8 + // discriminator
     8 + // last_updated
     1 + // version_count
     (32 + 256 + 8 + 32) * 10 + // recent_versions (max 10)
-    (8 + 8 + 4 + 2 + 8) + // execution_stats
+    (8 + 8 + 4 + 2 + 8 + 8 + 8 + 16 + 8 * LATENCY_HISTOGRAM_BUCKETS + 8 * FAILURE_CODE_COUNT) + // execution_stats
     (2 + 2 + 2 + 2) + // royalty_config
     4 + (64 + 64) * 5 + // tags (max 5)
-    (8 + 33 + 4 + 32 * 10 + 5) + // access_control
+    (8 + 33 + 4 + 32 * 10 + 5 + 9 + (1 + 4 + 8)) + // access_control
+    8 + // total_staked
+    8 + // slash_factor
+    1 + // slash_pending
+    1 + // validator_quorum
+    3 + // refund_policy
+    (8 + 8 + 9) + // metered_pricing
+    (1 + 8 + (8 + 8 + 9) + (2 + 2 + 2 + 2) + 3 + 1 + 8) + // pending_price_change
+    (8 + 8 + 4 + 4) + // execution_throttle
+    1 + // suspended_for_cause
     128; // padding
 
 pub const EXECUTION_RECORD_SIZE: usize = 8 + // discriminator
     64 + // prompt_id
+    4 + MAX_EXECUTION_ID_LEN + // execution_id
     32 + // caller
+    32 + // payer
+    32 + // executor
     32 + // version (max length)
     32 + // input_hash
     32 + // output_hash
@@ -168,13 +877,370 @@ pub const EXECUTION_RECORD_SIZE: usize = 8 + // discriminator
     8 + // execution_time_ms
     1 + // success
     256 + // error_message (optional)
+    2 + // failure_code
+    8 + // refunded_amount
+    1 + // status
+    8 + // challenge_deadline
+    (8 * 6) + // fee_breakdown
+    8 + // input_tokens
+    8 + // output_tokens
+    33 + // integrator
+    8 + // discount_amount
+    33 + // sponsor_pool
+    8 + // sponsored_amount
+    1 + // required_attestations
+    8 + // attestation_deadline
+    4 + 32 * MAX_EXECUTION_ATTESTERS + // attesters
+    1 + // from_job
     64; // padding
 
 pub const STAKE_ACCOUNT_SIZE: usize = 8 + // discriminator
     32 + // owner
     64 + // prompt_id
+    2 + // position_index
     8 + // staked_amount
     8 + // rewards_earned
+    8 + // pending_rewards
     8 + // last_claim
     8 + // stake_timestamp
-    64; // padding 
\ No newline at end of file
+    8 + // last_deposit
+    8 + // lockup_end
+    8 + // slash_factor_snapshot
+    64; // padding
+
+pub const SLASH_PROPOSAL_SIZE: usize = 8 + // discriminator
+    4 + 64 + // prompt_id
+    32 + // proposer
+    2 + // slash_bps
+    1 + // destination
+    4 + 128 + // reason
+    8 + // proposed_at
+    8 + // executable_at
+    32; // padding
+
+pub const EXECUTOR_ACCOUNT_SIZE: usize = 8 + // discriminator
+    32 + // authority
+    1 + // role
+    1 + // status
+    4 + 32 * 4 + // signing_keys (max 4)
+    4 + 256 + // metadata_uri
+    8 + // bond_amount
+    8 + // executions_attested
+    8 + // pending_rewards
+    4 + // open_settlements
+    8 + // registered_at
+    8 + // exit_requested_at
+    8 + // last_updated
+    64; // padding
+
+pub const DISPUTE_SIZE: usize = 8 + // discriminator
+    32 + // execution_record
+    32 + // challenger
+    8 + // bond_amount
+    4 + 256 + // evidence_uri
+    8 + // opened_at
+    8 + // resolve_deadline
+    4 + 32 * MAX_DISPUTE_VOTERS + // voters
+    1 + // uphold_votes
+    1 + // reject_votes
+    32; // padding
+
+pub const EXECUTION_NONCE_SIZE: usize = 8 + // discriminator
+    32 + // caller
+    8 + // highest_nonce
+    16 + // used_bitmap
+    8 + // last_updated
+    32; // padding
+
+pub const USER_USAGE_SIZE: usize = 8 + // discriminator
+    32 + // user
+    64 + // prompt_id
+    8 + // current_day
+    4 + // executions_today
+    8 + // total_executions
+    8 + // last_execution
+    32; // padding
+
+pub const ANALYTICS_BUCKET_SIZE: usize = 8 + // period
+    4 + // executions
+    4 + // successes
+    8 + // revenue
+    8; // latency_sum_ms
+
+pub const PROMPT_ANALYTICS_SIZE: usize = 8 + // discriminator
+    64 + // prompt_id
+    4 + ANALYTICS_BUCKET_SIZE * ANALYTICS_DAILY_BUCKETS + // daily
+    4 + ANALYTICS_BUCKET_SIZE * ANALYTICS_WEEKLY_BUCKETS + // weekly
+    8 + // last_updated
+    32; // padding
+
+pub const EXECUTION_JOB_SIZE: usize = 8 + // discriminator
+    64 + // prompt_id
+    4 + MAX_EXECUTION_ID_LEN + // job_id
+    32 + // caller
+    32 + // version (max length)
+    32 + // input_hash
+    8 + // escrowed_amount
+    1 + // status
+    8 + // created_at
+    8 + // expires_at
+    33 + // executor
+    32 + // output_hash
+    64 + // signature
+    8 + // execution_time_ms
+    8 + // input_tokens
+    8 + // output_tokens
+    1 + // success
+    2 + // failure_code
+    8 + // refunded_amount
+    (8 * 6) + // fee_breakdown
+    8 + // acceptance_deadline
+    32; // padding
+
+pub const INTEGRATOR_ACCOUNT_SIZE: usize = 8 + // discriminator
+    32 + // authority
+    2 + // fee_bps
+    8 + // total_executions
+    8 + // total_fees_earned
+    8 + // created_at
+    8 + // last_updated
+    32; // padding
+
+pub const SPONSOR_POOL_SIZE: usize = 8 + // discriminator
+    32 + // sponsor
+    64 + // prompt_id
+    8 + // balance
+    4 + 32 * MAX_SPONSOR_ALLOWLIST + // allowlist
+    9 + // per_user_cap
+    8 + // total_sponsored
+    8 + // executions_sponsored
+    8 + // created_at
+    8 + // last_updated
+    32; // padding
+
+pub const SPONSOR_USAGE_SIZE: usize = 8 + // discriminator
+    32 + // pool
+    32 + // user
+    8 + // amount_sponsored
+    8 + // executions_sponsored
+    16; // padding
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_waive_creator_share() {
+        let mut breakdown = FeeBreakdown {
+            total_fee: 1000,
+            creator_amount: 600,
+            dao_amount: 150,
+            validator_amount: 150,
+            burn_amount: 100,
+            integrator_amount: 0,
+        };
+        breakdown.waive_creator_share();
+        assert_eq!(breakdown.total_fee, 400);
+        assert_eq!(breakdown.creator_amount, 0);
+        assert_eq!(breakdown.dao_amount + breakdown.validator_amount + breakdown.burn_amount, 400);
+    }
+    
+    #[test]
+    fn test_revenue_excludes_integrator_share() {
+        let mut breakdown = FeeBreakdown {
+            total_fee: 1000,
+            creator_amount: 600,
+            dao_amount: 150,
+            validator_amount: 150,
+            burn_amount: 100,
+            integrator_amount: 0,
+        };
+        assert_eq!(breakdown.revenue(), 1000);
+        
+        // Charged on top, the integrator's share never belonged to the prompt
+        breakdown.add_integrator_share(500, IntegratorFeeSource::OnTop);
+        assert_eq!(breakdown.total_fee, 1050);
+        assert_eq!(breakdown.revenue(), 1000);
+    }
+    
+    #[test]
+    fn test_refund_policy_refund_amount() {
+        assert_eq!(RefundPolicy::None.refund_amount(1000), 0);
+        assert_eq!(RefundPolicy::Partial { refund_bps: 2500 }.refund_amount(1000), 250);
+        assert_eq!(RefundPolicy::Full.refund_amount(1000), 1000);
+        
+        assert!(RefundPolicy::Partial { refund_bps: 10000 }.validate().is_ok());
+        assert!(RefundPolicy::Partial { refund_bps: 10001 }.validate().is_err());
+    }
+    
+    #[test]
+    fn test_pause_windows_are_capped() {
+        let staking = StakingConfig {
+            emergency_withdraw_grace: MAX_EMERGENCY_WITHDRAW_GRACE,
+            ..Default::default()
+        };
+        assert!(staking.validate().is_ok());
+        let staking = StakingConfig {
+            emergency_withdraw_grace: MAX_EMERGENCY_WITHDRAW_GRACE + 1,
+            ..Default::default()
+        };
+        assert!(staking.validate().is_err());
+        
+        let slashing = SlashingConfig {
+            dispute_window: MAX_SLASH_DISPUTE_WINDOW,
+            ..Default::default()
+        };
+        assert!(slashing.validate().is_ok());
+        let slashing = SlashingConfig {
+            slash_bps: MAX_SLASH_BPS,
+            ..Default::default()
+        };
+        assert!(slashing.validate().is_ok());
+        let slashing = SlashingConfig {
+            slash_bps: 10000,
+            ..Default::default()
+        };
+        assert!(slashing.validate().is_err());
+        let slashing = SlashingConfig {
+            dispute_window: MAX_SLASH_DISPUTE_WINDOW + 1,
+            ..Default::default()
+        };
+        assert!(slashing.validate().is_err());
+    }
+    
+    #[test]
+    fn test_slash_proposal_emergency_withdraw_lock() {
+        let proposal = SlashProposal {
+            prompt_id: "prompt".to_string(),
+            proposer: Pubkey::default(),
+            slash_bps: 1000,
+            destination: SlashDestination::Treasury,
+            reason: String::new(),
+            proposed_at: 0,
+            executable_at: 1000,
+        };
+        assert!(proposal.locks_emergency_withdraw(500, 0));
+        assert!(proposal.locks_emergency_withdraw(500, 1499));
+        assert!(!proposal.locks_emergency_withdraw(500, 1500));
+        assert!(!proposal.locks_emergency_withdraw(0, 1000));
+    }
+    
+    #[test]
+    fn test_execution_throttle_cap() {
+        let access_control = AccessControl {
+            max_total_executions: Some(2),
+            ..Default::default()
+        };
+        let mut throttle = ExecutionThrottle::default();
+        assert_eq!(throttle.remaining_total(&access_control), Some(2));
+        assert!(throttle.admit(&access_control, 0).is_ok());
+        assert!(throttle.admit(&access_control, 0).is_ok());
+        assert_eq!(throttle.remaining_total(&access_control), Some(0));
+        assert!(throttle.admit(&access_control, 0).is_err());
+        
+        // A refunded job gives its slot back
+        throttle.release();
+        assert_eq!(throttle.remaining_total(&access_control), Some(1));
+        assert!(throttle.admit(&access_control, 0).is_ok());
+        
+        assert_eq!(throttle.remaining_total(&AccessControl::default()), None); // uncapped
+    }
+    
+    #[test]
+    fn test_user_usage_release_execution() {
+        let day = 10;
+        let mut usage = UserUsage {
+            user: Pubkey::default(),
+            prompt_id: "prompt".to_string(),
+            current_day: day,
+            executions_today: 2,
+            total_executions: 5,
+            last_execution: day * SECONDS_PER_DAY,
+        };
+        usage.release_execution(day * SECONDS_PER_DAY + 60);
+        assert_eq!(usage.remaining_daily_quota(Some(2), day * SECONDS_PER_DAY), Some(1));
+        assert_eq!(usage.total_executions, 4);
+        
+        // Executions recorded on an earlier day no longer count against today
+        usage.release_execution((day - 1) * SECONDS_PER_DAY);
+        assert_eq!(usage.executions_today, 1);
+        assert_eq!(usage.total_executions, 3);
+    }
+    
+    #[test]
+    fn test_execution_throttle_rate_limit() {
+        let access_control = AccessControl {
+            rate_limit: Some(RateLimit { max_executions: 2, window_slots: 100 }),
+            ..Default::default()
+        };
+        let mut throttle = ExecutionThrottle::default();
+        assert!(throttle.admit(&access_control, 100).is_ok());
+        assert!(throttle.admit(&access_control, 120).is_ok());
+        assert!(throttle.is_rate_limited(&access_control, 150));
+        assert!(throttle.admit(&access_control, 150).is_err());
+        
+        // Half the previous window still counts at slot 250
+        assert!(!throttle.is_rate_limited(&access_control, 250));
+        assert!(throttle.admit(&access_control, 250).is_ok());
+        assert!(throttle.is_rate_limited(&access_control, 260));
+        
+        assert!(!throttle.is_rate_limited(&access_control, 500)); // window rolled over
+        assert_eq!(throttle.admitted_executions, 3);
+    }
+    
+    fn test_breakdown() -> FeeBreakdown {
+        FeeBreakdown {
+            total_fee: 1000,
+            creator_amount: 600,
+            dao_amount: 150,
+            validator_amount: 150,
+            burn_amount: 100,
+            integrator_amount: 0,
+        }
+    }
+    
+    #[test]
+    fn test_add_integrator_share() {
+        let mut from_protocol = test_breakdown();
+        from_protocol.add_integrator_share(1000, IntegratorFeeSource::ProtocolShare);
+        assert_eq!(from_protocol.integrator_amount, 100);
+        assert_eq!(from_protocol.dao_amount, 50);
+        assert_eq!(from_protocol.total_fee, 1000);
+        
+        // The protocol share caps what the integrator can take from it
+        let mut capped = test_breakdown();
+        capped.add_integrator_share(2000, IntegratorFeeSource::ProtocolShare);
+        assert_eq!(capped.integrator_amount, 150);
+        assert_eq!(capped.dao_amount, 0);
+        assert_eq!(capped.total_fee, 1000);
+        
+        let mut on_top = test_breakdown();
+        on_top.add_integrator_share(1000, IntegratorFeeSource::OnTop);
+        assert_eq!(on_top.integrator_amount, 100);
+        assert_eq!(on_top.dao_amount, 150);
+        assert_eq!(on_top.total_fee, 1100);
+    }
+    
+    #[test]
+    fn test_discount_bps() {
+        let config = DiscountConfig {
+            tiers: vec![
+                DiscountTier { basis: DiscountBasis::Staked, min_amount: 1000, discount_bps: 500 },
+                DiscountTier { basis: DiscountBasis::Staked, min_amount: 10_000, discount_bps: 2000 },
+                DiscountTier { basis: DiscountBasis::Held, min_amount: 5000, discount_bps: 1000 },
+            ],
+        };
+        assert_eq!(config.discount_bps(0, 0), 0);
+        assert_eq!(config.discount_bps(1000, 0), 500);
+        assert_eq!(config.discount_bps(1000, 5000), 1000); // best tier wins
+        assert_eq!(config.discount_bps(10_000, 5000), 2000);
+        assert_eq!(config.discount_bps(0, 10_000), 1000);
+        assert_eq!(DiscountConfig::default().discount_bps(u64::MAX, u64::MAX), 0);
+        
+        assert!(config.validate().is_ok());
+        let invalid = DiscountConfig {
+            tiers: vec![DiscountTier { basis: DiscountBasis::Held, min_amount: 0, discount_bps: 10001 }],
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
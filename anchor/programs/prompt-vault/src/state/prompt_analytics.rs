@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Execution totals for one day or week
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AnalyticsBucket {
+    /// Day or week index the bucket currently holds
+    pub period: i64,
+    /// Executions recorded in the period
+    pub executions: u32,
+    /// Successful executions recorded in the period
+    pub successes: u32,
+    /// Fee revenue from successful executions distributed in the period
+    pub revenue: u64,
+    /// Sum of execution times in milliseconds
+    pub latency_sum_ms: u64,
+}
+
+impl AnalyticsBucket {
+    /// Reset the bucket if it holds an older period
+    fn roll_to(&mut self, period: i64) {
+        if self.period != period {
+            *self = AnalyticsBucket { period, ..Default::default() };
+        }
+    }
+
+    /// Add an execution
+    fn record(&mut self, period: i64, success: bool, execution_time_ms: u64) {
+        self.roll_to(period);
+        self.executions = self.executions.saturating_add(1);
+        if success {
+            self.successes = self.successes.saturating_add(1);
+        }
+        self.latency_sum_ms = self.latency_sum_ms.saturating_add(execution_time_ms);
+    }
+
+    /// Add revenue from a distributed fee
+    fn add_revenue(&mut self, period: i64, revenue: u64) {
+        self.roll_to(period);
+        self.revenue = self.revenue.saturating_add(revenue);
+    }
+}
+
+/// Windowed execution analytics for a prompt
+///
+/// Daily and weekly ring buffers indexed by `period % len`; a bucket whose
+/// `period` does not match the requested one is stale and reads as empty.
+#[account]
+pub struct PromptAnalytics {
+    /// Prompt the analytics belong to
+    pub prompt_id: String,
+    /// Daily buckets (ANALYTICS_DAILY_BUCKETS days)
+    pub daily: Vec<AnalyticsBucket>,
+    /// Weekly buckets (ANALYTICS_WEEKLY_BUCKETS weeks)
+    pub weekly: Vec<AnalyticsBucket>,
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl PromptAnalytics {
+    pub const SPACE: usize = PROMPT_ANALYTICS_SIZE;
+
+    /// Size the ring buffers for a freshly created account
+    pub fn initialize(&mut self, prompt_id: String) {
+        self.prompt_id = prompt_id;
+        self.daily = vec![AnalyticsBucket::default(); ANALYTICS_DAILY_BUCKETS];
+        self.weekly = vec![AnalyticsBucket::default(); ANALYTICS_WEEKLY_BUCKETS];
+    }
+
+    /// Check if the ring buffers have been sized
+    pub fn is_initialized(&self) -> bool {
+        !self.daily.is_empty()
+    }
+
+    /// Add an execution to the current day's and week's buckets
+    pub fn record_execution(&mut self, success: bool, execution_time_ms: u64) {
+        let now = Clock::get().unwrap().unix_timestamp;
+        let (day, week) = current_periods(now);
+        self.daily_bucket(day).record(day, success, execution_time_ms);
+        self.weekly_bucket(week).record(week, success, execution_time_ms);
+        self.last_updated = now;
+    }
+
+    /// Add a successful execution's distributed fee to the current day's and week's buckets
+    pub fn record_revenue(&mut self, revenue: u64) {
+        let now = Clock::get().unwrap().unix_timestamp;
+        let (day, week) = current_periods(now);
+        self.daily_bucket(day).add_revenue(day, revenue);
+        self.weekly_bucket(week).add_revenue(week, revenue);
+        self.last_updated = now;
+    }
+
+    /// Ring buffer slot holding `day`
+    fn daily_bucket(&mut self, day: i64) -> &mut AnalyticsBucket {
+        let len = self.daily.len() as i64;
+        &mut self.daily[day.rem_euclid(len) as usize]
+    }
+
+    /// Ring buffer slot holding `week`
+    fn weekly_bucket(&mut self, week: i64) -> &mut AnalyticsBucket {
+        let len = self.weekly.len() as i64;
+        &mut self.weekly[week.rem_euclid(len) as usize]
+    }
+}
+
+/// Day and week indices containing `now`
+fn current_periods(now: i64) -> (i64, i64) {
+    let day = now / SECONDS_PER_DAY;
+    (day, day / 7)
+}
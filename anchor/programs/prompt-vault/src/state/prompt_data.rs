@@ -38,6 +38,24 @@ pub struct PromptData {
     pub tags: Vec<PromptTag>,
     /// Access control settings
     pub access_control: AccessControl,
+    /// Total principal staked on this prompt
+    pub total_staked: u64,
+    /// Cumulative share of principal left after slashing (scaled by SLASH_FACTOR_PRECISION)
+    pub slash_factor: u64,
+    /// Whether a slash proposal is waiting out its dispute window
+    pub slash_pending: bool,
+    /// Validator attestations required before an execution's fee is released (0 = none)
+    pub validator_quorum: u8,
+    /// Refund applied to the fee of failed executions
+    pub refund_policy: RefundPolicy,
+    /// Counters for the access control execution cap and rate limit
+    pub execution_throttle: ExecutionThrottle,
+    /// Per-token pricing added to `fee_amount`
+    pub metered_pricing: MeteredPricing,
+    /// Scheduled price increase, applied once its notice period passes
+    pub pending_price_change: Option<PendingPriceChange>,
+    /// Whether the prompt was suspended by a slash proposal (only the admin or governance can lift it)
+    pub suspended_for_cause: bool,
 }
 
 impl PromptData {
@@ -63,6 +81,40 @@ impl PromptData {
         }
     }
 
+    /// Evaluate whether a user may execute the prompt right now
+    pub fn access_decision(
+        &self,
+        user: &Pubkey,
+        user_token_balance: u64,
+        usage: Option<&UserUsage>,
+    ) -> AccessDecision {
+        let clock = Clock::get().unwrap();
+        let now = clock.unix_timestamp;
+        let daily_limit = self.access_control.daily_usage_limit;
+        let license_granted = self.has_access(user, user_token_balance);
+        let remaining_daily_quota = match usage {
+            Some(usage) => usage.remaining_daily_quota(daily_limit, now),
+            None => daily_limit,
+        };
+        let remaining_total_executions = self.execution_throttle.remaining_total(&self.access_control);
+        let rate_limited = self.execution_throttle.is_rate_limited(&self.access_control, clock.slot);
+        
+        AccessDecision {
+            prompt_id: self.id.clone(),
+            user: *user,
+            license_type: self.license_type,
+            license_granted,
+            remaining_daily_quota,
+            remaining_total_executions,
+            rate_limited,
+            granted: license_granted &&
+                self.is_accessible() &&
+                remaining_daily_quota != Some(0) &&
+                remaining_total_executions != Some(0) &&
+                !rate_limited,
+        }
+    }
+
     /// Add a new version to the prompt
     pub fn add_version(&mut self, version: VersionEntry) -> Result<()> {
         // Limit to 10 most recent versions
@@ -79,31 +131,38 @@ impl PromptData {
         Ok(())
     }
 
+    /// Admit an execution under the prompt-wide cap and rate limit
+    pub fn admit_execution(&mut self, slot: u64) -> Result<()> {
+        self.execution_throttle.admit(&self.access_control, slot)
+    }
+
+    /// Release an admitted execution that was refunded without running
+    pub fn release_execution(&mut self) {
+        self.execution_throttle.release();
+    }
+
     /// Record an execution
-    pub fn record_execution(&mut self, execution_time_ms: u32, success: bool, revenue: u64) {
+    ///
+    /// Revenue is counted separately by `record_revenue` once the fee is distributed.
+    pub fn record_execution(
+        &mut self,
+        execution_time_ms: u64,
+        success: bool,
+        failure_code: Option<FailureCode>,
+    ) {
         self.execution_count = self.execution_count.saturating_add(1);
-        self.execution_stats.total_executions = self.execution_stats.total_executions.saturating_add(1);
         
-        if success {
-            self.execution_stats.total_revenue = self.execution_stats.total_revenue.saturating_add(revenue);
-        }
-        
-        // Update average execution time
-        let total_time = (self.execution_stats.avg_execution_time as u64) * (self.execution_stats.total_executions - 1);
-        self.execution_stats.avg_execution_time = ((total_time + execution_time_ms as u64) / self.execution_stats.total_executions) as u32;
-        
-        // Update success rate
-        let successful_executions = if success {
-            ((self.execution_stats.success_rate as u64) * (self.execution_stats.total_executions - 1) / 10000) + 1
-        } else {
-            (self.execution_stats.success_rate as u64) * (self.execution_stats.total_executions - 1) / 10000
-        };
-        self.execution_stats.success_rate = ((successful_executions * 10000) / self.execution_stats.total_executions) as u16;
+        self.execution_stats.record(execution_time_ms, success, failure_code);
         
         self.execution_stats.last_execution = Clock::get().unwrap().unix_timestamp;
         self.touch();
     }
 
+    /// Count a successful execution's fee as revenue once it has been distributed
+    pub fn record_revenue(&mut self, revenue: u64) {
+        self.execution_stats.total_revenue = self.execution_stats.total_revenue.saturating_add(revenue);
+    }
+
     /// Update the last modified timestamp
     pub fn touch(&mut self) {
         self.last_updated = Clock::get().unwrap().unix_timestamp;
@@ -111,14 +170,139 @@ impl PromptData {
 
     /// Calculate fee distribution based on royalty config
     pub fn calculate_fee_distribution(&self, total_fee: u64) -> (u64, u64, u64, u64) {
-        let creator_amount = (total_fee * self.royalty_config.creator_share_bps as u64) / 10000;
-        let dao_amount = (total_fee * self.royalty_config.dao_share_bps as u64) / 10000;
-        let validator_amount = (total_fee * self.royalty_config.validator_share_bps as u64) / 10000;
+        let creator_amount = crate::utils::calculate_bps_amount(total_fee, self.royalty_config.creator_share_bps);
+        let dao_amount = crate::utils::calculate_bps_amount(total_fee, self.royalty_config.dao_share_bps);
+        let validator_amount = crate::utils::calculate_bps_amount(total_fee, self.royalty_config.validator_share_bps);
         let burn_amount = total_fee - creator_amount - dao_amount - validator_amount;
         
         (creator_amount, dao_amount, validator_amount, burn_amount)
     }
 
+    /// Slash `slash_bps` of the stake on this prompt, returning the amount removed
+    pub fn apply_slash(&mut self, slash_bps: u16) -> u64 {
+        let slash_bps = slash_bps.min(MAX_SLASH_BPS);
+        let slashed = crate::utils::calculate_bps_amount(self.total_staked, slash_bps);
+        self.total_staked = self.total_staked.saturating_sub(slashed);
+        self.slash_factor = crate::utils::reduce_slash_factor(self.slash_factor, slash_bps);
+        self.slash_pending = false;
+        slashed
+    }
+
+    /// Check if moving to the given pricing would raise any part of the price
+    pub fn is_price_increase(&self, fee_amount: u64, metered_pricing: &MeteredPricing) -> bool {
+        let current = &self.metered_pricing;
+        let max_fee_raised = match (current.max_fee, metered_pricing.max_fee) {
+            (Some(current_max), Some(new_max)) => new_max > current_max,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        
+        fee_amount > self.fee_amount ||
+            metered_pricing.price_per_1k_input_tokens > current.price_per_1k_input_tokens ||
+            metered_pricing.price_per_1k_output_tokens > current.price_per_1k_output_tokens ||
+            max_fee_raised
+    }
+
+    /// Terms callers pay under now
+    pub fn price_terms(&self) -> PriceTerms {
+        PriceTerms {
+            fee_amount: self.fee_amount,
+            metered_pricing: self.metered_pricing,
+            royalty_config: self.royalty_config,
+            refund_policy: self.refund_policy,
+            validator_quorum: self.validator_quorum,
+        }
+    }
+
+    /// Terms callers will pay under once any pending change applies
+    pub fn scheduled_price_terms(&self) -> PriceTerms {
+        self.pending_price_change.map_or(self.price_terms(), |pending| pending.terms())
+    }
+
+    /// Change the price, fee split, refund policy and validator quorum
+    ///
+    /// Price decreases and more generous refunds apply at once; price
+    /// increases, refund cuts and split or quorum changes wait out
+    /// `notice_period`. Any change supersedes a pending one.
+    pub fn schedule_price_change(&mut self, terms: PriceTerms, notice_period: i64, now: i64) {
+        if notice_period <= 0 {
+            self.set_price_terms(&terms);
+            self.pending_price_change = None;
+            return;
+        }
+        
+        self.fee_amount = self.fee_amount.min(terms.fee_amount);
+        self.metered_pricing = self.metered_pricing.lower_of(&terms.metered_pricing);
+        let refund_cut = terms.refund_policy.refund_bps() < self.refund_policy.refund_bps();
+        if !refund_cut {
+            self.refund_policy = terms.refund_policy;
+        }
+        let needs_notice = self.is_price_increase(terms.fee_amount, &terms.metered_pricing) ||
+            terms.royalty_config != self.royalty_config ||
+            refund_cut ||
+            terms.validator_quorum != self.validator_quorum;
+        self.pending_price_change = needs_notice.then_some(PendingPriceChange {
+            fee_amount: terms.fee_amount,
+            metered_pricing: terms.metered_pricing,
+            royalty_config: terms.royalty_config,
+            refund_policy: terms.refund_policy,
+            validator_quorum: terms.validator_quorum,
+            effective_at: now.saturating_add(notice_period),
+        });
+    }
+
+    /// Apply the pending price change if its notice period has passed
+    pub fn apply_due_price_change(&mut self, now: i64) {
+        if let Some(pending) = self.pending_price_change {
+            if now >= pending.effective_at {
+                self.set_price_terms(&pending.terms());
+                self.pending_price_change = None;
+            }
+        }
+    }
+
+    fn set_price_terms(&mut self, terms: &PriceTerms) {
+        self.fee_amount = terms.fee_amount;
+        self.metered_pricing = terms.metered_pricing;
+        self.royalty_config = terms.royalty_config;
+        self.refund_policy = terms.refund_policy;
+        self.validator_quorum = terms.validator_quorum;
+    }
+
+    /// Fee for an execution that used the given token counts, capped at `max_fee`
+    pub fn execution_fee(&self, input_tokens: u64, output_tokens: u64) -> u64 {
+        let pricing = &self.metered_pricing;
+        let fee = self.fee_amount
+            .saturating_add(crate::utils::calculate_token_charge(input_tokens, pricing.price_per_1k_input_tokens))
+            .saturating_add(crate::utils::calculate_token_charge(output_tokens, pricing.price_per_1k_output_tokens));
+        
+        pricing.max_fee.map_or(fee, |max_fee| fee.min(max_fee))
+    }
+
+    /// Most an execution can be charged, or None if metered without a cap
+    pub fn max_execution_fee(&self) -> Option<u64> {
+        if self.metered_pricing.is_metered() {
+            self.metered_pricing.max_fee
+        } else {
+            Some(self.execution_fee(0, 0))
+        }
+    }
+
+    /// Calculate the fee breakdown for a total fee
+    pub fn fee_breakdown(&self, total_fee: u64) -> FeeBreakdown {
+        let (creator_amount, dao_amount, validator_amount, burn_amount) =
+            self.calculate_fee_distribution(total_fee);
+        
+        FeeBreakdown {
+            total_fee,
+            creator_amount,
+            dao_amount,
+            validator_amount,
+            burn_amount,
+            integrator_amount: 0,
+        }
+    }
+
     /// Get the latest version entry
     pub fn get_latest_version(&self) -> Option<&VersionEntry> {
         self.recent_versions.last()
@@ -185,6 +369,10 @@ impl RegisterPromptParams {
         require!(!self.version.is_empty(), crate::errors::VaultError::EmptyVersion);
         require!(self.version.len() <= 32, crate::errors::VaultError::VersionTooLong);
         require!(self.tags.len() <= 5, crate::errors::VaultError::TooManyTags);
+        require!(
+            crate::utils::validate_access_control(&self.access_control),
+            crate::errors::VaultError::InvalidAccessControl
+        );
         
         // Validate royalty config if provided
         if let Some(ref config) = self.royalty_config {
@@ -238,6 +426,12 @@ pub struct UpdateLicenseParams {
     pub access_control: Option<AccessControl>,
     /// New royalty configuration
     pub royalty_config: Option<RoyaltyConfig>,
+    /// New validator quorum for executions
+    pub validator_quorum: Option<u8>,
+    /// New refund policy for failed executions
+    pub refund_policy: Option<RefundPolicy>,
+    /// New per-token pricing
+    pub metered_pricing: Option<MeteredPricing>,
 }
 
 /// Parameters for updating status
@@ -272,4 +466,184 @@ pub struct ForkPromptParams {
     pub tags: Vec<PromptTag>,
     /// Access control for the fork
     pub access_control: AccessControl,
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_prompt(fee_amount: u64, metered_pricing: MeteredPricing) -> PromptData {
+        PromptData {
+            id: "prompt".to_string(),
+            author: Pubkey::new_unique(),
+            metadata_uri: String::new(),
+            current_version: "1.0.0".to_string(),
+            license_type: LicenseType::Public,
+            fee_amount,
+            token_gate: None,
+            execution_count: 0,
+            status: PromptStatus::Active,
+            created_at: 0,
+            last_updated: 0,
+            version_count: 1,
+            recent_versions: Vec::new(),
+            execution_stats: ExecutionStats::default(),
+            royalty_config: RoyaltyConfig::default(),
+            tags: Vec::new(),
+            access_control: AccessControl::default(),
+            total_staked: 0,
+            slash_factor: SLASH_FACTOR_PRECISION,
+            slash_pending: false,
+            validator_quorum: 0,
+            refund_policy: RefundPolicy::None,
+            execution_throttle: ExecutionThrottle::default(),
+            metered_pricing,
+            pending_price_change: None,
+            suspended_for_cause: false,
+        }
+    }
+    
+    #[test]
+    fn test_execution_fee() {
+        let flat = test_prompt(100, MeteredPricing::default());
+        assert_eq!(flat.execution_fee(0, 0), 100);
+        assert_eq!(flat.execution_fee(5000, 5000), 100);
+        assert_eq!(flat.max_execution_fee(), Some(100));
+        
+        let metered = test_prompt(100, MeteredPricing {
+            price_per_1k_input_tokens: 10,
+            price_per_1k_output_tokens: 30,
+            max_fee: None,
+        });
+        assert_eq!(metered.execution_fee(2000, 1000), 100 + 20 + 30);
+        assert_eq!(metered.execution_fee(1, 1), 100 + 1 + 1); // token charges round up
+        assert_eq!(metered.max_execution_fee(), None);
+        
+        let capped = test_prompt(100, MeteredPricing {
+            price_per_1k_input_tokens: 10,
+            price_per_1k_output_tokens: 30,
+            max_fee: Some(200),
+        });
+        assert_eq!(capped.execution_fee(2000, 1000), 150);
+        assert_eq!(capped.execution_fee(100_000, 100_000), 200);
+        assert_eq!(capped.max_execution_fee(), Some(200));
+    }
+    
+    #[test]
+    fn test_apply_slash() {
+        let mut prompt = test_prompt(100, MeteredPricing::default());
+        prompt.total_staked = 1000;
+        prompt.slash_pending = true;
+        
+        assert_eq!(prompt.apply_slash(1000), 100);
+        assert_eq!(prompt.total_staked, 900);
+        assert_eq!(prompt.slash_factor, SLASH_FACTOR_PRECISION / 10 * 9);
+        assert!(!prompt.slash_pending);
+        
+        // A full slash is capped, so some stake and a nonzero factor survive
+        assert_eq!(prompt.apply_slash(10000), 891);
+        assert_eq!(prompt.total_staked, 9);
+        assert_eq!(prompt.slash_factor, SLASH_FACTOR_PRECISION / 1000 * 9);
+    }
+    
+    #[test]
+    fn test_is_price_increase() {
+        let pricing = MeteredPricing {
+            price_per_1k_input_tokens: 10,
+            price_per_1k_output_tokens: 30,
+            max_fee: Some(200),
+        };
+        let prompt = test_prompt(100, pricing);
+        assert!(!prompt.is_price_increase(100, &pricing));
+        assert!(!prompt.is_price_increase(50, &MeteredPricing { max_fee: Some(150), ..pricing }));
+        assert!(prompt.is_price_increase(101, &pricing));
+        assert!(prompt.is_price_increase(100, &MeteredPricing { price_per_1k_output_tokens: 31, ..pricing }));
+        assert!(prompt.is_price_increase(100, &MeteredPricing { max_fee: None, ..pricing })); // cap removed
+    }
+    
+    #[test]
+    fn test_schedule_price_change() {
+        let royalty = RoyaltyConfig::default();
+        let pricing = MeteredPricing {
+            price_per_1k_input_tokens: 10,
+            price_per_1k_output_tokens: 30,
+            max_fee: None,
+        };
+        
+        // Decreases apply at once
+        let mut prompt = test_prompt(100, pricing);
+        let terms = prompt.price_terms();
+        prompt.schedule_price_change(PriceTerms { fee_amount: 50, ..terms }, 3600, 1000);
+        assert_eq!(prompt.fee_amount, 50);
+        assert!(prompt.pending_price_change.is_none());
+        
+        // Increases wait out the notice period
+        prompt.schedule_price_change(PriceTerms { fee_amount: 200, ..terms }, 3600, 1000);
+        assert_eq!(prompt.fee_amount, 50);
+        assert_eq!(prompt.pending_price_change.map(|pending| pending.effective_at), Some(4600));
+        prompt.apply_due_price_change(4599);
+        assert_eq!(prompt.fee_amount, 50);
+        prompt.apply_due_price_change(4600);
+        assert_eq!(prompt.fee_amount, 200);
+        assert!(prompt.pending_price_change.is_none());
+        
+        // A mixed change applies its decreases now and the rest after notice
+        let raised = MeteredPricing { price_per_1k_input_tokens: 20, ..pricing };
+        let terms = PriceTerms { fee_amount: 150, metered_pricing: raised, ..terms };
+        prompt.schedule_price_change(terms, 3600, 5000);
+        assert_eq!(prompt.fee_amount, 150);
+        assert_eq!(prompt.metered_pricing.price_per_1k_input_tokens, 10);
+        prompt.apply_due_price_change(8600);
+        assert_eq!(prompt.fee_amount, 150);
+        assert_eq!(prompt.metered_pricing.price_per_1k_input_tokens, 20);
+        
+        // Fee split changes need notice too
+        let split = RoyaltyConfig { creator_share_bps: 7000, burn_share_bps: 0, ..royalty };
+        prompt.schedule_price_change(PriceTerms { royalty_config: split, ..terms }, 3600, 9000);
+        assert!(prompt.royalty_config == royalty);
+        assert!(prompt.pending_price_change.is_some());
+        prompt.apply_due_price_change(12600);
+        assert!(prompt.royalty_config == split);
+        
+        // Without a notice period everything applies at once
+        let terms = PriceTerms { fee_amount: 300, metered_pricing: pricing, ..terms };
+        prompt.schedule_price_change(terms, 0, 13000);
+        assert_eq!(prompt.fee_amount, 300);
+        assert!(prompt.royalty_config == royalty);
+        assert!(prompt.pending_price_change.is_none());
+    }
+    
+    #[test]
+    fn test_schedule_refund_and_quorum_change() {
+        let mut prompt = test_prompt(100, MeteredPricing::default());
+        prompt.refund_policy = RefundPolicy::Partial { refund_bps: 5000 };
+        let terms = prompt.price_terms();
+        
+        // More generous refunds apply at once
+        prompt.schedule_price_change(PriceTerms { refund_policy: RefundPolicy::Full, ..terms }, 3600, 1000);
+        assert!(prompt.refund_policy == RefundPolicy::Full);
+        assert!(prompt.pending_price_change.is_none());
+        
+        // Refund cuts wait out the notice period
+        let terms = prompt.price_terms();
+        prompt.schedule_price_change(PriceTerms { refund_policy: RefundPolicy::None, ..terms }, 3600, 2000);
+        assert!(prompt.refund_policy == RefundPolicy::Full);
+        prompt.apply_due_price_change(5599);
+        assert!(prompt.refund_policy == RefundPolicy::Full);
+        prompt.apply_due_price_change(5600);
+        assert!(prompt.refund_policy == RefundPolicy::None);
+        
+        // Quorum changes need notice in either direction
+        let terms = prompt.price_terms();
+        prompt.schedule_price_change(PriceTerms { validator_quorum: 2, ..terms }, 3600, 6000);
+        assert_eq!(prompt.validator_quorum, 0);
+        assert!(prompt.scheduled_price_terms().validator_quorum == 2);
+        prompt.apply_due_price_change(9600);
+        assert_eq!(prompt.validator_quorum, 2);
+        
+        let terms = prompt.price_terms();
+        prompt.schedule_price_change(PriceTerms { validator_quorum: 1, ..terms }, 3600, 10000);
+        assert_eq!(prompt.validator_quorum, 2);
+        assert!(prompt.pending_price_change.is_some());
+    }
+}
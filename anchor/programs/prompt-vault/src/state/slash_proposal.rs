@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Pending slash of the stake backing a suspended or removed prompt
+///
+/// The proposal is public for the whole dispute window so stakers can see
+/// exactly what will be slashed before it executes.
+#[account]
+pub struct SlashProposal {
+    /// Prompt whose stake is being slashed
+    pub prompt_id: String,
+    /// Admin or governance authority that proposed the slash
+    pub proposer: Pubkey,
+    /// Share of the prompt's stake to slash, in basis points
+    pub slash_bps: u16,
+    /// Where slashed stake is sent
+    pub destination: SlashDestination,
+    /// Reason for the slash
+    pub reason: String,
+    /// Proposal timestamp
+    pub proposed_at: i64,
+    /// Earliest time the slash can be executed
+    pub executable_at: i64,
+}
+
+impl SlashProposal {
+    pub const SPACE: usize = SLASH_PROPOSAL_SIZE;
+
+    /// Check if the dispute window has elapsed
+    pub fn is_executable(&self) -> bool {
+        Clock::get().unwrap().unix_timestamp >= self.executable_at
+    }
+
+    /// Check if the slash still blocks emergency withdrawals
+    ///
+    /// Stake stays locked for the dispute window plus the emergency withdraw
+    /// grace, so an unexecuted proposal cannot hold stake through a pause forever.
+    pub fn locks_emergency_withdraw(&self, emergency_withdraw_grace: i64, now: i64) -> bool {
+        now < self.executable_at.saturating_add(emergency_withdraw_grace)
+    }
+}
+
+/// Parameters for proposing a slash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposeSlashParams {
+    /// Prompt ID
+    pub prompt_id: String,
+    /// Reason for the slash
+    pub reason: String,
+}
+
+impl ProposeSlashParams {
+    /// Validate the proposal parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.prompt_id.is_empty(), crate::errors::VaultError::EmptyPromptId);
+        require!(self.reason.len() <= 128, crate::errors::VaultError::SlashReasonTooLong);
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// Tokens a sponsor set aside to pay for other users' executions of a prompt
+#[account]
+pub struct SponsorPool {
+    /// Wallet that funds the pool and may withdraw from it
+    pub sponsor: Pubkey,
+    /// Prompt whose executions the pool pays for
+    pub prompt_id: String,
+    /// Tokens left for sponsored executions, held in the sponsor escrow
+    pub balance: u64,
+    /// Users the pool pays for (empty = anyone)
+    pub allowlist: Vec<Pubkey>,
+    /// Most the pool pays in total for any one user (None = uncapped)
+    pub per_user_cap: Option<u64>,
+    /// Fees paid by the pool all-time
+    pub total_sponsored: u64,
+    /// Executions paid by the pool all-time
+    pub executions_sponsored: u64,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Last update timestamp
+    pub last_updated: i64,
+}
+
+impl SponsorPool {
+    pub const SPACE: usize = SPONSOR_POOL_SIZE;
+
+    /// Check if the account has been written by `sponsor_prompt`
+    pub fn is_initialized(&self) -> bool {
+        self.created_at != 0
+    }
+
+    /// Check if the pool can pay `fee` for `user`, who has drawn `user_sponsored` so far
+    pub fn is_eligible(&self, user: &Pubkey, user_sponsored: u64, fee: u64) -> bool {
+        let allowed = self.allowlist.is_empty() || self.allowlist.contains(user);
+        let over_cap = self.per_user_cap
+            .is_some_and(|cap| user_sponsored.saturating_add(fee) > cap);
+        
+        allowed && !over_cap && self.balance >= fee
+    }
+
+    /// Pay `fee` for an execution out of the pool
+    pub fn record_sponsorship(&mut self, fee: u64) {
+        self.balance -= fee;
+        self.total_sponsored = self.total_sponsored.saturating_add(fee);
+        self.executions_sponsored = self.executions_sponsored.saturating_add(1);
+        self.touch();
+    }
+
+    /// Return a refunded sponsored fee to the pool
+    pub fn credit_refund(&mut self, amount: u64) {
+        self.balance = self.balance.saturating_add(amount);
+        self.touch();
+    }
+
+    /// Update the last modified timestamp
+    pub fn touch(&mut self) {
+        self.last_updated = Clock::get().unwrap().unix_timestamp;
+    }
+}
+
+/// A user's draw on a sponsor pool, used to enforce its per-user cap
+#[account]
+pub struct SponsorUsage {
+    /// Sponsor pool drawn on
+    pub pool: Pubkey,
+    /// User whose executions were sponsored
+    pub user: Pubkey,
+    /// Fees the pool paid for the user
+    pub amount_sponsored: u64,
+    /// Executions the pool paid for the user
+    pub executions_sponsored: u64,
+}
+
+impl SponsorUsage {
+    pub const SPACE: usize = SPONSOR_USAGE_SIZE;
+
+    /// Count a sponsored execution
+    pub fn record_sponsorship(&mut self, fee: u64) {
+        self.amount_sponsored = self.amount_sponsored.saturating_add(fee);
+        self.executions_sponsored = self.executions_sponsored.saturating_add(1);
+    }
+
+    /// Release a refunded sponsored fee from the user's cap
+    pub fn credit_refund(&mut self, amount: u64) {
+        self.amount_sponsored = self.amount_sponsored.saturating_sub(amount);
+    }
+}
+
+/// Parameters for funding a sponsor pool and setting its terms
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SponsorPromptParams {
+    /// Prompt to sponsor
+    pub prompt_id: String,
+    /// Tokens to deposit (may be 0 to only update the terms)
+    pub amount: u64,
+    /// Users the pool pays for (empty = anyone)
+    pub allowlist: Vec<Pubkey>,
+    /// Most the pool pays in total for any one user
+    pub per_user_cap: Option<u64>,
+}
+
+impl SponsorPromptParams {
+    /// Validate the sponsorship parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(!self.prompt_id.is_empty(), crate::errors::VaultError::EmptyPromptId);
+        require!(
+            self.allowlist.len() <= MAX_SPONSOR_ALLOWLIST && self.per_user_cap != Some(0),
+            crate::errors::VaultError::InvalidSponsorTerms
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_pool(allowlist: Vec<Pubkey>, per_user_cap: Option<u64>, balance: u64) -> SponsorPool {
+        SponsorPool {
+            sponsor: Pubkey::new_unique(),
+            prompt_id: "prompt".to_string(),
+            balance,
+            allowlist,
+            per_user_cap,
+            total_sponsored: 0,
+            executions_sponsored: 0,
+            created_at: 1,
+            last_updated: 1,
+        }
+    }
+    
+    #[test]
+    fn test_is_eligible() {
+        let user = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        
+        let open = test_pool(Vec::new(), None, 1000);
+        assert!(open.is_eligible(&user, 0, 1000));
+        assert!(open.is_eligible(&other, 1_000_000, 500));
+        assert!(!open.is_eligible(&user, 0, 1001)); // balance too low
+        
+        let allowlisted = test_pool(vec![user], None, 1000);
+        assert!(allowlisted.is_eligible(&user, 0, 100));
+        assert!(!allowlisted.is_eligible(&other, 0, 100));
+        
+        let capped = test_pool(Vec::new(), Some(500), 1000);
+        assert!(capped.is_eligible(&user, 400, 100));
+        assert!(!capped.is_eligible(&user, 401, 100));
+        assert!(!capped.is_eligible(&user, u64::MAX, 1)); // saturates instead of wrapping
+    }
+}
@@ -2,41 +2,93 @@ use anchor_lang::prelude::*;
 use super::*;
 
 /// Stake account for prompt ranking and rewards
+///
+/// A staker may hold several positions on the same prompt, each addressed by
+/// its own `position_index` and carrying an independent lockup.
 #[account]
 pub struct StakeAccount {
     /// Owner of the stake
     pub owner: Pubkey,
     /// Prompt ID being staked for
     pub prompt_id: String,
+    /// Index of this position among the owner's positions on the prompt
+    pub position_index: u16,
     /// Amount of tokens staked
     pub staked_amount: u64,
     /// Accumulated rewards
     pub rewards_earned: u64,
-    /// Last time rewards were claimed
+    /// Rewards settled on top-ups but not yet claimed
+    pub pending_rewards: u64,
+    /// Last time rewards were claimed or settled
     pub last_claim: i64,
     /// When the stake was created
     pub stake_timestamp: i64,
+    /// When tokens were last deposited into the position
+    pub last_deposit: i64,
+    /// Principal cannot be unstaked before this timestamp
+    pub lockup_end: i64,
+    /// Prompt slash factor when the balance was last brought up to date
+    pub slash_factor_snapshot: u64,
 }
 
 impl StakeAccount {
     pub const SPACE: usize = STAKE_ACCOUNT_SIZE;
 
     /// Create a new stake account
-    pub fn new(owner: Pubkey, prompt_id: String, initial_stake: u64) -> Self {
+    pub fn new(
+        owner: Pubkey,
+        prompt_id: String,
+        position_index: u16,
+        initial_stake: u64,
+        lockup_duration: i64,
+        slash_factor: u64,
+    ) -> Self {
         let now = Clock::get().unwrap().unix_timestamp;
         Self {
             owner,
             prompt_id,
+            position_index,
             staked_amount: initial_stake,
             rewards_earned: 0,
+            pending_rewards: 0,
             last_claim: now,
             stake_timestamp: now,
+            last_deposit: now,
+            lockup_end: now.saturating_add(lockup_duration),
+            slash_factor_snapshot: slash_factor,
         }
     }
 
+    /// Apply any slashing executed on the prompt since the last snapshot
+    pub fn apply_slashing(&mut self, prompt_slash_factor: u64) {
+        self.staked_amount = crate::utils::apply_slash_factor(
+            self.staked_amount,
+            prompt_slash_factor,
+            self.slash_factor_snapshot,
+        );
+        self.slash_factor_snapshot = prompt_slash_factor;
+    }
+
+    /// Staked balance after slashing executed on the prompt since the last snapshot
+    pub fn effective_stake(&self, prompt_slash_factor: u64) -> u64 {
+        crate::utils::apply_slash_factor(
+            self.staked_amount,
+            prompt_slash_factor,
+            self.slash_factor_snapshot,
+        )
+    }
+
     /// Add more tokens to the stake
     pub fn add_stake(&mut self, amount: u64) {
         self.staked_amount = self.staked_amount.saturating_add(amount);
+        self.last_deposit = Clock::get().unwrap().unix_timestamp;
+    }
+
+    /// Move rewards accrued at the current balance into `pending_rewards`
+    pub fn settle_rewards(&mut self, config: &StakingConfig) {
+        let accrued = self.calculate_pending_rewards(config);
+        self.pending_rewards = self.pending_rewards.saturating_add(accrued);
+        self.last_claim = Clock::get().unwrap().unix_timestamp;
     }
 
     /// Remove tokens from the stake
@@ -49,19 +101,23 @@ impl StakeAccount {
         Ok(())
     }
 
-    /// Calculate pending rewards based on time staked and amount
-    pub fn calculate_pending_rewards(&self, reward_rate_per_second: u64) -> u64 {
+    /// Calculate pending rewards accrued inside the emission schedule since the last claim
+    pub fn calculate_pending_rewards(&self, config: &StakingConfig) -> u64 {
         let current_time = Clock::get().unwrap().unix_timestamp;
-        let time_since_last_claim = (current_time - self.last_claim) as u64;
+        let emission_seconds = config.emission_seconds(self.last_claim, current_time);
         
         // Simple linear reward calculation
-        // In practice, this would be more sophisticated
-        (self.staked_amount * reward_rate_per_second * time_since_last_claim) / 1_000_000
+        let rewards = (self.staked_amount as u128)
+            .saturating_mul(config.reward_rate_per_second as u128)
+            .saturating_mul(emission_seconds as u128)
+            / 1_000_000;
+        rewards.min(u64::MAX as u128) as u64
     }
 
     /// Claim accumulated rewards
     pub fn claim_rewards(&mut self, reward_amount: u64) {
         self.rewards_earned = self.rewards_earned.saturating_add(reward_amount);
+        self.pending_rewards = 0;
         self.last_claim = Clock::get().unwrap().unix_timestamp;
     }
 
@@ -76,9 +132,81 @@ impl StakeAccount {
         self.staked_amount.saturating_add(self.rewards_earned)
     }
 
+    /// Check if the position is still inside its lockup
+    pub fn is_locked(&self) -> bool {
+        Clock::get().unwrap().unix_timestamp < self.lockup_end
+    }
+
+    /// Check if the latest deposit is older than the given duration
+    pub fn deposit_older_than(&self, duration_seconds: i64) -> bool {
+        let current_time = Clock::get().unwrap().unix_timestamp;
+        current_time - self.last_deposit >= duration_seconds
+    }
+
     /// Check if the stake has been active for a certain duration
     pub fn has_been_staked_for(&self, duration_seconds: i64) -> bool {
         let current_time = Clock::get().unwrap().unix_timestamp;
         current_time - self.stake_timestamp >= duration_seconds
     }
-} 
\ No newline at end of file
+}
+
+/// Parameters for opening a new stake position
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakeForRankingParams {
+    /// Index of the new position
+    pub position_index: u16,
+    /// Amount of tokens to stake
+    pub amount: u64,
+    /// Lockup applied to the position (seconds, 0 = none)
+    pub lockup_duration: i64,
+}
+
+impl StakeForRankingParams {
+    /// Validate the staking parameters
+    pub fn validate(&self) -> Result<()> {
+        require!(self.lockup_duration >= 0, crate::errors::VaultError::InvalidTimestamp);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_stake(staked_amount: u64, slash_factor_snapshot: u64) -> StakeAccount {
+        StakeAccount {
+            owner: Pubkey::new_unique(),
+            prompt_id: "prompt".to_string(),
+            position_index: 0,
+            staked_amount,
+            rewards_earned: 0,
+            pending_rewards: 0,
+            last_claim: 0,
+            stake_timestamp: 0,
+            last_deposit: 0,
+            lockup_end: 0,
+            slash_factor_snapshot,
+        }
+    }
+    
+    #[test]
+    fn test_apply_slashing() {
+        let first_slash = crate::utils::reduce_slash_factor(SLASH_FACTOR_PRECISION, 1000);
+        let second_slash = crate::utils::reduce_slash_factor(first_slash, 5000);
+        let mut early = test_stake(1000, SLASH_FACTOR_PRECISION);
+        let mut late = test_stake(500, first_slash); // opened after the first slash
+        
+        assert_eq!(early.effective_stake(first_slash), 900);
+        assert_eq!(early.effective_stake(second_slash), 450);
+        assert_eq!(late.effective_stake(second_slash), 250);
+        
+        // Settling moves the snapshot, so a slash is only applied once
+        early.apply_slashing(first_slash);
+        assert_eq!(early.staked_amount, 900);
+        early.apply_slashing(second_slash);
+        early.apply_slashing(second_slash);
+        assert_eq!(early.staked_amount, 450);
+        late.apply_slashing(second_slash);
+        assert_eq!(late.staked_amount, 250);
+    }
+}
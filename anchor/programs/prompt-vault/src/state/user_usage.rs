@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use super::*;
+
+/// A user's execution usage of a prompt, used to enforce quotas
+#[account]
+pub struct UserUsage {
+    /// User the usage belongs to
+    pub user: Pubkey,
+    /// Prompt being used
+    pub prompt_id: String,
+    /// Day index (unix timestamp / SECONDS_PER_DAY) that `executions_today` counts
+    pub current_day: i64,
+    /// Executions recorded on `current_day`
+    pub executions_today: u32,
+    /// Executions recorded all-time
+    pub total_executions: u64,
+    /// Last execution timestamp
+    pub last_execution: i64,
+}
+
+impl UserUsage {
+    pub const SPACE: usize = USER_USAGE_SIZE;
+
+    /// Read the usage account at its PDA, or None if it was never created
+    ///
+    /// Anything other than an empty address must be a valid usage account, so
+    /// a caller cannot pass an uninitialized stand-in to skip its quota.
+    pub fn load_at(info: &AccountInfo) -> Result<Option<Self>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require!(info.owner == &crate::ID, crate::errors::VaultError::InvalidAccountData);
+        
+        let data = info.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Executions counted against the daily limit on `day`
+    pub fn executions_on(&self, day: i64) -> u32 {
+        if self.current_day == day {
+            self.executions_today
+        } else {
+            0
+        }
+    }
+
+    /// Executions left today under `daily_limit` (None if unlimited)
+    pub fn remaining_daily_quota(&self, daily_limit: Option<u32>, now: i64) -> Option<u32> {
+        daily_limit.map(|limit| limit.saturating_sub(self.executions_on(now / SECONDS_PER_DAY)))
+    }
+
+    /// Count an execution, enforcing the daily limit
+    pub fn record_execution(&mut self, daily_limit: Option<u32>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            self.remaining_daily_quota(daily_limit, now) != Some(0),
+            crate::errors::VaultError::DailyUsageLimitExceeded
+        );
+        
+        let day = now / SECONDS_PER_DAY;
+        self.executions_today = self.executions_on(day).saturating_add(1);
+        self.current_day = day;
+        self.total_executions = self.total_executions.saturating_add(1);
+        self.last_execution = now;
+        Ok(())
+    }
+
+    /// Uncount an execution recorded at `recorded_at` that was refunded without running
+    ///
+    /// The daily count is only given back while it is still the same day.
+    pub fn release_execution(&mut self, recorded_at: i64) {
+        if self.current_day == recorded_at / SECONDS_PER_DAY {
+            self.executions_today = self.executions_today.saturating_sub(1);
+        }
+        self.total_executions = self.total_executions.saturating_sub(1);
+    }
+}
+
+/// Access decision returned by `check_access`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AccessDecision {
+    /// Prompt ID
+    pub prompt_id: String,
+    /// User the decision is for
+    pub user: Pubkey,
+    /// Prompt's license type
+    pub license_type: LicenseType,
+    /// Whether the user satisfies the license terms
+    pub license_granted: bool,
+    /// Executions left today (None if the prompt has no daily limit)
+    pub remaining_daily_quota: Option<u32>,
+    /// Executions left under the prompt's total cap (None if uncapped)
+    pub remaining_total_executions: Option<u64>,
+    /// Whether the prompt-wide rate limit is currently exhausted
+    pub rate_limited: bool,
+    /// Whether the user may execute the prompt right now
+    pub granted: bool,
+}
@@ -8,6 +8,8 @@ pub struct VaultState {
     pub admin: Pubkey,
     /// Treasury account for protocol fees
     pub treasury: Pubkey,
+    /// Token mint execution fees are paid in
+    pub payment_mint: Pubkey,
     /// Total number of registered prompts
     pub prompt_count: u64,
     /// Protocol fee in basis points (10000 = 100%)
@@ -18,10 +20,32 @@ pub struct VaultState {
     pub validator_share_bps: u16,
     /// Emergency pause flag
     pub is_paused: bool,
+    /// When the current pause started (0 when not paused)
+    pub paused_at: i64,
     /// Vault creation timestamp
     pub created_at: i64,
     /// Last update timestamp
     pub last_updated: i64,
+    /// Staking economics enforced by the staking instructions
+    pub staking_config: StakingConfig,
+    /// Stake slashing configuration
+    pub slashing_config: SlashingConfig,
+    /// Minimum age before an execution record can be closed (seconds)
+    pub execution_retention_period: i64,
+    /// Executor and validator registry configuration
+    pub registry_config: RegistryConfig,
+    /// Optimistic execution dispute configuration
+    pub dispute_config: DisputeConfig,
+    /// Execution job queue configuration
+    pub job_config: JobConfig,
+    /// Delay before a prompt price increase takes effect (seconds)
+    pub price_change_notice_period: i64,
+    /// Referral and integrator fee configuration
+    pub integrator_config: IntegratorConfig,
+    /// Holder and staker fee discount tiers
+    pub discount_config: DiscountConfig,
+    /// Registered validators currently able to attest executions
+    pub active_validators: u32,
 }
 
 impl VaultState {
@@ -38,11 +62,38 @@ impl VaultState {
         total <= 10000 // Allow for burn percentage
     }
 
+    /// Check if a key may arbitrate execution disputes
+    pub fn is_dispute_arbiter(&self, key: &Pubkey) -> bool {
+        *key == self.admin || self.dispute_config.arbiter == Some(*key)
+    }
+
+    /// Check if stakers may emergency withdraw: paused and past the grace window
+    ///
+    /// `emergency_withdraw` additionally refuses prompts whose pending slash still locks stake.
+    pub fn is_emergency_withdraw_open(&self, now: i64) -> bool {
+        self.is_paused &&
+            now.saturating_sub(self.paused_at) >= self.staking_config.emergency_withdraw_grace
+    }
+
     /// Update the last modified timestamp
     pub fn touch(&mut self) {
         self.last_updated = Clock::get().unwrap().unix_timestamp;
     }
 
+    /// Check if a key may propose or cancel stake slashing
+    pub fn is_slashing_authority(&self, key: &Pubkey) -> bool {
+        *key == self.admin || self.slashing_config.governance == Some(*key)
+    }
+
+    /// Keep the active validator count in step with an executor's status change
+    pub fn update_active_validators(&mut self, was_validating: bool, is_validating: bool) {
+        if is_validating && !was_validating {
+            self.active_validators = self.active_validators.saturating_add(1);
+        } else if was_validating && !is_validating {
+            self.active_validators = self.active_validators.saturating_sub(1);
+        }
+    }
+
     /// Increment prompt count
     pub fn increment_prompt_count(&mut self) {
         self.prompt_count = self.prompt_count.saturating_add(1);
@@ -50,17 +101,81 @@ impl VaultState {
     }
 }
 
+/// Vault state as laid out before the configurable economics, read by `migrate_vault_state`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyVaultState {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub prompt_count: u64,
+    pub protocol_fee_bps: u16,
+    pub creator_share_bps: u16,
+    pub validator_share_bps: u16,
+    pub is_paused: bool,
+    pub created_at: i64,
+    pub last_updated: i64,
+}
+
+impl VaultState {
+    /// Carry a legacy vault over to the current layout, taking the new settings from `params`
+    pub fn from_legacy(legacy: LegacyVaultState, params: MigrateVaultStateParams, now: i64) -> Self {
+        Self {
+            admin: legacy.admin,
+            treasury: legacy.treasury,
+            payment_mint: params.payment_mint,
+            prompt_count: legacy.prompt_count,
+            protocol_fee_bps: legacy.protocol_fee_bps,
+            creator_share_bps: legacy.creator_share_bps,
+            validator_share_bps: legacy.validator_share_bps,
+            is_paused: legacy.is_paused,
+            // The legacy layout did not record when a pause started; the grace period runs from now
+            paused_at: if legacy.is_paused { now } else { 0 },
+            created_at: legacy.created_at,
+            last_updated: now,
+            staking_config: params.staking_config,
+            slashing_config: params.slashing_config,
+            execution_retention_period: params.execution_retention_period,
+            registry_config: params.registry_config,
+            dispute_config: params.dispute_config,
+            job_config: params.job_config,
+            price_change_notice_period: params.price_change_notice_period,
+            integrator_config: params.integrator_config,
+            discount_config: params.discount_config,
+            active_validators: 0,
+        }
+    }
+}
+
 /// Parameters for initializing the vault
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitializeParams {
     /// Treasury account for collecting fees
     pub treasury: Pubkey,
+    /// Token mint execution fees are paid in
+    pub payment_mint: Pubkey,
     /// Protocol fee in basis points
     pub protocol_fee_bps: u16,
     /// Creator royalty share in basis points
     pub creator_share_bps: u16,
     /// Validator reward share in basis points
     pub validator_share_bps: u16,
+    /// Initial staking configuration
+    pub staking_config: StakingConfig,
+    /// Initial slashing configuration
+    pub slashing_config: SlashingConfig,
+    /// Minimum age before an execution record can be closed (seconds)
+    pub execution_retention_period: i64,
+    /// Initial executor registry configuration
+    pub registry_config: RegistryConfig,
+    /// Initial dispute configuration
+    pub dispute_config: DisputeConfig,
+    /// Initial job queue configuration
+    pub job_config: JobConfig,
+    /// Delay before a prompt price increase takes effect (seconds)
+    pub price_change_notice_period: i64,
+    /// Initial integrator fee configuration
+    pub integrator_config: IntegratorConfig,
+    /// Initial fee discount tiers
+    pub discount_config: DiscountConfig,
 }
 
 impl InitializeParams {
@@ -73,6 +188,226 @@ impl InitializeParams {
         // Ensure treasury is not the default pubkey
         require!(self.treasury != Pubkey::default(), crate::errors::VaultError::InvalidTreasury);
         
+        require!(self.execution_retention_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        require!(self.price_change_notice_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        
+        self.staking_config.validate()?;
+        self.slashing_config.validate()?;
+        self.registry_config.validate()?;
+        self.dispute_config.validate()?;
+        self.job_config.validate()?;
+        self.integrator_config.validate()?;
+        self.discount_config.validate()
+    }
+}
+
+/// Settings introduced since the legacy vault layout, supplied when migrating it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateVaultStateParams {
+    /// Token mint execution fees are paid in
+    pub payment_mint: Pubkey,
+    /// Staking configuration
+    pub staking_config: StakingConfig,
+    /// Slashing configuration
+    pub slashing_config: SlashingConfig,
+    /// Minimum age before an execution record can be closed (seconds)
+    pub execution_retention_period: i64,
+    /// Executor registry configuration
+    pub registry_config: RegistryConfig,
+    /// Dispute configuration
+    pub dispute_config: DisputeConfig,
+    /// Job queue configuration
+    pub job_config: JobConfig,
+    /// Delay before a prompt price increase takes effect (seconds)
+    pub price_change_notice_period: i64,
+    /// Integrator fee configuration
+    pub integrator_config: IntegratorConfig,
+    /// Fee discount tiers
+    pub discount_config: DiscountConfig,
+}
+
+impl MigrateVaultStateParams {
+    /// Validate the migrated settings
+    pub fn validate(&self) -> Result<()> {
+        require!(self.execution_retention_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        require!(self.price_change_notice_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        
+        self.staking_config.validate()?;
+        self.slashing_config.validate()?;
+        self.registry_config.validate()?;
+        self.dispute_config.validate()?;
+        self.job_config.validate()?;
+        self.integrator_config.validate()?;
+        self.discount_config.validate()
+    }
+}
+
+/// Parameters for updating the vault configuration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateVaultConfigParams {
+    /// New staking configuration
+    pub staking_config: Option<StakingConfig>,
+    /// New slashing configuration
+    pub slashing_config: Option<SlashingConfig>,
+    /// New execution record retention period
+    pub execution_retention_period: Option<i64>,
+    /// New executor registry configuration
+    pub registry_config: Option<RegistryConfig>,
+    /// New dispute configuration
+    pub dispute_config: Option<DisputeConfig>,
+    /// New job queue configuration
+    pub job_config: Option<JobConfig>,
+    /// New price increase notice period
+    pub price_change_notice_period: Option<i64>,
+    /// New integrator fee configuration
+    pub integrator_config: Option<IntegratorConfig>,
+    /// New fee discount tiers
+    pub discount_config: Option<DiscountConfig>,
+}
+
+impl UpdateVaultConfigParams {
+    /// Validate the configuration update
+    pub fn validate(&self) -> Result<()> {
+        if let Some(ref staking_config) = self.staking_config {
+            staking_config.validate()?;
+        }
+        
+        if let Some(ref slashing_config) = self.slashing_config {
+            slashing_config.validate()?;
+        }
+        
+        if let Some(retention_period) = self.execution_retention_period {
+            require!(retention_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        }
+        
+        if let Some(ref registry_config) = self.registry_config {
+            registry_config.validate()?;
+        }
+        
+        if let Some(ref dispute_config) = self.dispute_config {
+            dispute_config.validate()?;
+        }
+        
+        if let Some(ref job_config) = self.job_config {
+            job_config.validate()?;
+        }
+        
+        if let Some(notice_period) = self.price_change_notice_period {
+            require!(notice_period >= 0, crate::errors::VaultError::InvalidTimestamp);
+        }
+        
+        if let Some(ref integrator_config) = self.integrator_config {
+            integrator_config.validate()?;
+        }
+        
+        if let Some(ref discount_config) = self.discount_config {
+            discount_config.validate()?;
+        }
+        
         Ok(())
     }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn test_vault_state() -> VaultState {
+        VaultState {
+            admin: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            payment_mint: Pubkey::new_unique(),
+            prompt_count: 0,
+            protocol_fee_bps: 250,
+            creator_share_bps: 7000,
+            validator_share_bps: 1000,
+            is_paused: false,
+            paused_at: 0,
+            created_at: 0,
+            last_updated: 0,
+            staking_config: StakingConfig::default(),
+            slashing_config: SlashingConfig::default(),
+            execution_retention_period: 0,
+            registry_config: RegistryConfig::default(),
+            dispute_config: DisputeConfig::default(),
+            job_config: JobConfig::default(),
+            price_change_notice_period: 0,
+            integrator_config: IntegratorConfig::default(),
+            discount_config: DiscountConfig::default(),
+            active_validators: 0,
+        }
+    }
+    
+    #[test]
+    fn test_is_emergency_withdraw_open() {
+        let mut vault_state = test_vault_state();
+        vault_state.staking_config.emergency_withdraw_grace = 3600;
+        assert!(!vault_state.is_emergency_withdraw_open(10_000));
+        
+        // Only once the vault has been paused for the whole grace period
+        vault_state.is_paused = true;
+        vault_state.paused_at = 7000;
+        assert!(!vault_state.is_emergency_withdraw_open(10_599));
+        assert!(vault_state.is_emergency_withdraw_open(10_600));
+        
+        vault_state.staking_config.emergency_withdraw_grace = 0;
+        assert!(vault_state.is_emergency_withdraw_open(7000));
+    }
+    
+    #[test]
+    fn test_dispute_arbiter_is_separate_from_slashing_authority() {
+        let mut vault_state = test_vault_state();
+        let governance = Pubkey::new_unique();
+        let arbiter = Pubkey::new_unique();
+        vault_state.slashing_config.governance = Some(governance);
+        vault_state.dispute_config.arbiter = Some(arbiter);
+        
+        assert!(vault_state.is_dispute_arbiter(&vault_state.admin));
+        assert!(vault_state.is_dispute_arbiter(&arbiter));
+        assert!(!vault_state.is_dispute_arbiter(&governance));
+        assert!(vault_state.is_slashing_authority(&vault_state.admin));
+        assert!(vault_state.is_slashing_authority(&governance));
+        assert!(!vault_state.is_slashing_authority(&arbiter));
+    }
+    
+    #[test]
+    fn test_migrate_legacy_vault_state() {
+        let legacy = LegacyVaultState {
+            admin: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            prompt_count: 42,
+            protocol_fee_bps: 250,
+            creator_share_bps: 7000,
+            validator_share_bps: 1000,
+            is_paused: true,
+            created_at: 100,
+            last_updated: 200,
+        };
+        assert!(8 + legacy.try_to_vec().unwrap().len() <= LEGACY_VAULT_STATE_SIZE);
+        
+        let params = MigrateVaultStateParams {
+            payment_mint: Pubkey::new_unique(),
+            staking_config: StakingConfig::default(),
+            slashing_config: SlashingConfig::default(),
+            execution_retention_period: 0,
+            registry_config: RegistryConfig::default(),
+            dispute_config: DisputeConfig::default(),
+            job_config: JobConfig::default(),
+            price_change_notice_period: 0,
+            integrator_config: IntegratorConfig::default(),
+            discount_config: DiscountConfig::default(),
+        };
+        let vault_state = VaultState::from_legacy(legacy.clone(), params.clone(), 5000);
+        assert_eq!(vault_state.admin, legacy.admin);
+        assert_eq!(vault_state.treasury, legacy.treasury);
+        assert_eq!(vault_state.payment_mint, params.payment_mint);
+        assert_eq!(vault_state.prompt_count, 42);
+        assert_eq!(vault_state.created_at, 100);
+        assert_eq!(vault_state.paused_at, 5000); // grace period starts at migration
+        assert_eq!(vault_state.last_updated, 5000);
+        
+        let mut data = Vec::new();
+        vault_state.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= VAULT_STATE_SIZE);
+    }
+}
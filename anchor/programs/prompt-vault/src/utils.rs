@@ -1,8 +1,9 @@
+//! Utility functions for the PromptVault program
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, sysvar::instructions as instructions_sysvar};
 use sha2::{Digest, Sha256};
 
-/// Utility functions for the PromptVault program
-
 /// Generate a content hash from prompt data
 pub fn generate_content_hash(content: &str) -> [u8; 32] {
     let mut hasher = Sha256::new();
@@ -32,9 +33,128 @@ pub fn bps_to_percentage(bps: u16) -> f64 {
     (bps as f64) / 100.0
 }
 
+/// Index of the latency histogram bucket an execution time falls into
+pub fn latency_bucket_index(execution_time_ms: u64) -> usize {
+    crate::state::LATENCY_BUCKET_BOUNDS_MS
+        .iter()
+        .position(|&bound| execution_time_ms <= bound)
+        .unwrap_or(crate::state::LATENCY_BUCKET_BOUNDS_MS.len())
+}
+
+/// Upper bound of the histogram bucket containing the given percentile
+///
+/// Returns `None` for an empty histogram and `u64::MAX` when the percentile
+/// lands in the open-ended overflow bucket.
+pub fn histogram_percentile(histogram: &[u64], percentile_bps: u16) -> Option<u64> {
+    let total: u128 = histogram.iter().map(|&count| count as u128).sum();
+    if total == 0 {
+        return None;
+    }
+    
+    // Rank of the sample at the percentile (1-based, rounded up)
+    let rank = (total * percentile_bps.min(10000) as u128).div_ceil(10000);
+    let rank = rank.max(1);
+    
+    let mut seen: u128 = 0;
+    for (index, &count) in histogram.iter().enumerate() {
+        seen += count as u128;
+        if seen >= rank {
+            return Some(
+                crate::state::LATENCY_BUCKET_BOUNDS_MS
+                    .get(index)
+                    .copied()
+                    .unwrap_or(u64::MAX),
+            );
+        }
+    }
+    Some(u64::MAX)
+}
+
 /// Calculate amount from basis points
 pub fn calculate_bps_amount(total: u64, bps: u16) -> u64 {
-    (total * bps as u64) / 10000
+    ((total as u128 * bps as u128) / 10000) as u64
+}
+
+/// Price of `tokens` at `price_per_1k` per 1,000 tokens, rounded up
+pub fn calculate_token_charge(tokens: u64, price_per_1k: u64) -> u64 {
+    let charge = (tokens as u128 * price_per_1k as u128).div_ceil(1000);
+    charge.min(u64::MAX as u128) as u64
+}
+
+/// Scale a stake balance by the slashing applied since its factor snapshot
+pub fn apply_slash_factor(amount: u64, current_factor: u64, snapshot_factor: u64) -> u64 {
+    if snapshot_factor == 0 || current_factor >= snapshot_factor {
+        return amount;
+    }
+    
+    ((amount as u128 * current_factor as u128) / snapshot_factor as u128) as u64
+}
+
+/// Cumulative slash factor after slashing a further `slash_bps`
+///
+/// Never returns zero, since `apply_slash_factor` reads a zero snapshot as
+/// "nothing slashed yet".
+pub fn reduce_slash_factor(factor: u64, slash_bps: u16) -> u64 {
+    let slash_bps = slash_bps.min(10000) as u128;
+    let reduced = (factor as u128 * (10000 - slash_bps)) / 10000;
+    (reduced as u64).max(1)
+}
+
+/// Number of nonces tracked below the highest nonce seen
+pub const NONCE_WINDOW: u64 = 128;
+
+/// Mark `nonce` as used in a sliding window anchored at `highest`
+///
+/// Bit `i` of `bitmap` records whether `highest - i` has been used. Returns the
+/// updated window, or `None` if the nonce was already used or fell out of the window.
+pub fn consume_nonce(highest: u64, bitmap: u128, nonce: u64) -> Option<(u64, u128)> {
+    if nonce > highest {
+        let shift = nonce - highest;
+        let shifted = if shift >= NONCE_WINDOW { 0 } else { bitmap << shift };
+        return Some((nonce, shifted | 1));
+    }
+    
+    let offset = highest - nonce;
+    if offset >= NONCE_WINDOW || bitmap & (1u128 << offset) != 0 {
+        return None;
+    }
+    Some((highest, bitmap | (1u128 << offset)))
+}
+
+/// Roll a sliding window execution counter forward to `slot`
+///
+/// Windows are aligned to multiples of `window_slots`. Returns the updated
+/// `(window_start_slot, current_count, previous_count)`.
+pub fn roll_execution_window(
+    window_start_slot: u64,
+    current_count: u32,
+    previous_count: u32,
+    window_slots: u64,
+    slot: u64,
+) -> (u64, u32, u32) {
+    let aligned_start = slot - slot % window_slots;
+    if aligned_start <= window_start_slot {
+        (window_start_slot, current_count, previous_count)
+    } else if aligned_start - window_start_slot == window_slots {
+        (aligned_start, 0, current_count)
+    } else {
+        (aligned_start, 0, 0)
+    }
+}
+
+/// Executions in the `window_slots` ending at `slot`, estimated by weighting
+/// the previous window's count by how much of it is still inside the window
+pub fn sliding_window_count(
+    window_start_slot: u64,
+    current_count: u32,
+    previous_count: u32,
+    window_slots: u64,
+    slot: u64,
+) -> u64 {
+    let elapsed = slot.saturating_sub(window_start_slot).min(window_slots);
+    let weighted = previous_count as u128 * (window_slots - elapsed) as u128;
+    let overlap = weighted.div_ceil(window_slots as u128) as u64;
+    current_count as u64 + overlap
 }
 
 /// Validate IPFS URI format
@@ -42,12 +162,15 @@ pub fn validate_ipfs_uri(uri: &str) -> bool {
     uri.starts_with("ipfs://") || uri.starts_with("https://ipfs.io/ipfs/")
 }
 
-/// Generate a unique execution ID
-pub fn generate_execution_id(prompt_id: &str, caller: &Pubkey, timestamp: i64) -> String {
+/// Generate a deterministic execution ID from a caller-chosen nonce
+///
+/// Clients can derive the execution record address ahead of time from the
+/// result, and retrying with the same nonce maps to the same record.
+pub fn generate_execution_id(prompt_id: &str, caller: &Pubkey, nonce: u64) -> String {
     let mut hasher = Sha256::new();
     hasher.update(prompt_id.as_bytes());
     hasher.update(caller.as_ref());
-    hasher.update(&timestamp.to_le_bytes());
+    hasher.update(nonce.to_le_bytes());
     
     let hash = hasher.finalize();
     bs58::encode(&hash[..16]).into_string() // Use first 16 bytes for shorter ID
@@ -120,9 +243,94 @@ pub fn validate_access_control(access_control: &crate::state::AccessControl) ->
         }
     }
     
+    if access_control.max_total_executions == Some(0) {
+        return false;
+    }
+    
+    if let Some(ref rate_limit) = access_control.rate_limit {
+        if rate_limit.max_executions == 0 || rate_limit.window_slots == 0 {
+            return false;
+        }
+    }
+    
     true
 }
 
+/// A signature checked by an Ed25519 program instruction in the current transaction
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ed25519SignatureEntry {
+    pub pubkey: Pubkey,
+    pub signature: [u8; 64],
+    pub message: Vec<u8>,
+}
+
+/// Size of one signature offsets entry in Ed25519 program instruction data
+const ED25519_OFFSETS_SIZE: usize = 14;
+
+/// Parse the signatures carried by an Ed25519 program instruction
+///
+/// Only signatures whose key, signature and message all live in the Ed25519
+/// instruction itself are accepted; offsets into other instructions are rejected.
+pub fn parse_ed25519_instruction(data: &[u8]) -> Result<Vec<Ed25519SignatureEntry>> {
+    require!(data.len() >= 2, crate::errors::VaultError::InvalidExecutionSignature);
+    let num_signatures = data[0] as usize;
+    
+    let read_u16 = |offset: usize| -> Result<u16> {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .ok_or_else(|| error!(crate::errors::VaultError::InvalidExecutionSignature))
+    };
+    let slice = |offset: u16, len: usize| -> Result<&[u8]> {
+        let start = offset as usize;
+        data.get(start..start + len)
+            .ok_or_else(|| error!(crate::errors::VaultError::InvalidExecutionSignature))
+    };
+    
+    let mut entries = Vec::with_capacity(num_signatures);
+    for i in 0..num_signatures {
+        let base = 2 + i * ED25519_OFFSETS_SIZE;
+        let signature_offset = read_u16(base)?;
+        let signature_ix_index = read_u16(base + 2)?;
+        let pubkey_offset = read_u16(base + 4)?;
+        let pubkey_ix_index = read_u16(base + 6)?;
+        let message_offset = read_u16(base + 8)?;
+        let message_size = read_u16(base + 10)?;
+        let message_ix_index = read_u16(base + 12)?;
+        
+        require!(
+            signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+            crate::errors::VaultError::InvalidExecutionSignature
+        );
+        
+        let mut signature = [0u8; 64];
+        signature.copy_from_slice(slice(signature_offset, 64)?);
+        
+        entries.push(Ed25519SignatureEntry {
+            pubkey: Pubkey::try_from(slice(pubkey_offset, 32)?)
+                .map_err(|_| error!(crate::errors::VaultError::InvalidExecutionSignature))?,
+            signature,
+            message: slice(message_offset, message_size as usize)?.to_vec(),
+        });
+    }
+    
+    Ok(entries)
+}
+
+/// Collect every signature verified by Ed25519 program instructions preceding the current one
+pub fn load_ed25519_signatures(instructions: &AccountInfo) -> Result<Vec<Ed25519SignatureEntry>> {
+    let current_index = instructions_sysvar::load_current_index_checked(instructions)?;
+    
+    let mut entries = Vec::new();
+    for index in 0..current_index {
+        let ix = instructions_sysvar::load_instruction_at_checked(index as usize, instructions)?;
+        if ix.program_id == ed25519_program::ID {
+            entries.extend(parse_ed25519_instruction(&ix.data)?);
+        }
+    }
+    
+    Ok(entries)
+}
+
 /// Calculate time-weighted average for execution statistics
 pub fn calculate_time_weighted_average(
     current_avg: u32,
@@ -166,6 +374,144 @@ mod tests {
         assert_eq!(calculate_bps_amount(1000, 10000), 1000); // 100%
     }
     
+    #[test]
+    fn test_calculate_token_charge() {
+        assert_eq!(calculate_token_charge(0, 500), 0);
+        assert_eq!(calculate_token_charge(1000, 500), 500);
+        assert_eq!(calculate_token_charge(1500, 500), 750);
+        assert_eq!(calculate_token_charge(1, 500), 1); // rounded up
+        assert_eq!(calculate_token_charge(u64::MAX, u64::MAX), u64::MAX); // saturates
+    }
+    
+    #[test]
+    fn test_apply_slash_factor() {
+        let full = crate::state::SLASH_FACTOR_PRECISION;
+        assert_eq!(apply_slash_factor(1000, full, full), 1000);
+        assert_eq!(apply_slash_factor(1000, full / 2, full), 500); // 50% slashed
+        assert_eq!(apply_slash_factor(1000, full / 4, full / 2), 500); // only slashing since snapshot
+        assert_eq!(apply_slash_factor(1000, full, 0), 1000); // no snapshot
+    }
+    
+    #[test]
+    fn test_reduce_slash_factor() {
+        let full = crate::state::SLASH_FACTOR_PRECISION;
+        assert_eq!(reduce_slash_factor(full, 1000), full / 10 * 9);
+        assert_eq!(reduce_slash_factor(full, crate::state::MAX_SLASH_BPS), full / 100);
+        
+        // A full slash leaves a factor that still reads as a snapshot
+        let wiped = reduce_slash_factor(full, 10000);
+        assert_eq!(wiped, 1);
+        assert_eq!(apply_slash_factor(1000, wiped, full), 0);
+        assert_eq!(apply_slash_factor(1000, reduce_slash_factor(wiped, 5000), wiped), 1000);
+        
+        // Repeated slashes bottom out rather than wrapping to "no snapshot"
+        let mut factor = full;
+        for _ in 0..10 {
+            factor = reduce_slash_factor(factor, crate::state::MAX_SLASH_BPS);
+        }
+        assert_eq!(factor, 1);
+        assert_eq!(apply_slash_factor(1000, factor, full), 0);
+    }
+    
+    #[test]
+    fn test_latency_bucket_index() {
+        assert_eq!(latency_bucket_index(0), 0);
+        assert_eq!(latency_bucket_index(50), 0);
+        assert_eq!(latency_bucket_index(51), 1);
+        assert_eq!(latency_bucket_index(1_000), 4);
+        assert_eq!(latency_bucket_index(120_000), 10);
+        assert_eq!(latency_bucket_index(u64::MAX), 11); // overflow bucket
+    }
+    
+    #[test]
+    fn test_histogram_percentile() {
+        let mut histogram = [0u64; crate::state::LATENCY_HISTOGRAM_BUCKETS];
+        assert_eq!(histogram_percentile(&histogram, 5000), None);
+        
+        histogram[0] = 90; // <= 50ms
+        histogram[4] = 9; // <= 1s
+        histogram[11] = 1; // > 120s
+        assert_eq!(histogram_percentile(&histogram, 5000), Some(50));
+        assert_eq!(histogram_percentile(&histogram, 9000), Some(50));
+        assert_eq!(histogram_percentile(&histogram, 9500), Some(1_000));
+        assert_eq!(histogram_percentile(&histogram, 9900), Some(1_000));
+        assert_eq!(histogram_percentile(&histogram, 10000), Some(u64::MAX));
+    }
+    
+    #[test]
+    fn test_consume_nonce() {
+        let (highest, bitmap) = consume_nonce(0, 0, 0).unwrap();
+        assert_eq!((highest, bitmap), (0, 1));
+        assert!(consume_nonce(highest, bitmap, 0).is_none()); // replay
+        
+        let (highest, bitmap) = consume_nonce(highest, bitmap, 5).unwrap();
+        assert_eq!(highest, 5);
+        assert!(consume_nonce(highest, bitmap, 0).is_none()); // still tracked
+        let (highest, bitmap) = consume_nonce(highest, bitmap, 3).unwrap(); // out of order
+        assert!(consume_nonce(highest, bitmap, 3).is_none());
+        
+        let (highest, bitmap) = consume_nonce(highest, bitmap, 5 + NONCE_WINDOW).unwrap();
+        assert_eq!(bitmap, 1);
+        assert!(consume_nonce(highest, bitmap, 5).is_none()); // fell out of the window
+        assert!(consume_nonce(highest, bitmap, 6).is_some());
+    }
+    
+    #[test]
+    fn test_sliding_window_count() {
+        let window = 100;
+        assert_eq!(roll_execution_window(0, 0, 0, window, 250), (200, 0, 0));
+        assert_eq!(roll_execution_window(200, 3, 0, window, 299), (200, 3, 0)); // same window
+        assert_eq!(roll_execution_window(200, 3, 1, window, 310), (300, 0, 3)); // next window
+        assert_eq!(roll_execution_window(200, 3, 1, window, 410), (400, 0, 0)); // skipped a window
+        
+        assert_eq!(sliding_window_count(300, 2, 10, window, 300), 12); // previous fully counted
+        assert_eq!(sliding_window_count(300, 2, 10, window, 350), 7); // half the previous window
+        assert_eq!(sliding_window_count(300, 2, 10, window, 399), 3); // rounded up
+        assert_eq!(sliding_window_count(300, 2, 10, window, 400), 2);
+    }
+    
+    fn build_ed25519_data(pubkey: &Pubkey, signature: &[u8; 64], message: &[u8], ix_index: u16) -> Vec<u8> {
+        let header = 2 + ED25519_OFFSETS_SIZE;
+        let pubkey_offset = header as u16;
+        let signature_offset = pubkey_offset + 32;
+        let message_offset = signature_offset + 64;
+        
+        let mut data = vec![1u8, 0u8];
+        for value in [
+            signature_offset,
+            ix_index,
+            pubkey_offset,
+            ix_index,
+            message_offset,
+            message.len() as u16,
+            ix_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(pubkey.as_ref());
+        data.extend_from_slice(signature);
+        data.extend_from_slice(message);
+        data
+    }
+    
+    #[test]
+    fn test_parse_ed25519_instruction() {
+        let pubkey = Pubkey::new_unique();
+        let signature = [7u8; 64];
+        let message = generate_content_hash("execution");
+        
+        let entries = parse_ed25519_instruction(&build_ed25519_data(&pubkey, &signature, &message, u16::MAX)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].pubkey, pubkey);
+        assert_eq!(entries[0].signature, signature);
+        assert_eq!(entries[0].message, message.to_vec());
+        
+        // Offsets pointing at another instruction are rejected
+        assert!(parse_ed25519_instruction(&build_ed25519_data(&pubkey, &signature, &message, 0)).is_err());
+        // Truncated data is rejected
+        assert!(parse_ed25519_instruction(&[1u8, 0u8, 0u8]).is_err());
+    }
+    
     #[test]
     fn test_are_versions_compatible() {
         assert!(are_versions_compatible("1.0.0", "1.1.0"));
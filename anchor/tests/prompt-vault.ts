@@ -207,6 +207,7 @@ describe("prompt-vault", () => {
   it("Stake for ranking", async () => {
     const promptId = "test-prompt-1";
     const stakeAmount = 5000 * 10**9; // 5000 tokens
+    const positionIndex = 0;
 
     const [promptData] = PublicKey.findProgramAddressSync(
       [Buffer.from("prompt"), Buffer.from(promptId)],
@@ -214,7 +215,12 @@ describe("prompt-vault", () => {
     );
 
     const [stakeAccount] = PublicKey.findProgramAddressSync(
-      [Buffer.from("stake"), Buffer.from(promptId), user.publicKey.toBuffer()],
+      [
+        Buffer.from("stake"),
+        Buffer.from(promptId),
+        user.publicKey.toBuffer(),
+        new anchor.BN(positionIndex).toArrayLike(Buffer, "le", 2),
+      ],
       program.programId
     );
 
@@ -224,7 +230,11 @@ describe("prompt-vault", () => {
     );
 
     await program.methods
-      .stakeForRanking(new anchor.BN(stakeAmount))
+      .stakeForRanking({
+        positionIndex,
+        amount: new anchor.BN(stakeAmount),
+        lockupDuration: new anchor.BN(0),
+      })
       .accounts({
         stakeAccount,
         promptData,
@@ -241,6 +251,7 @@ describe("prompt-vault", () => {
     const stakeAccountData = await program.account.stakeAccount.fetch(stakeAccount);
     expect(stakeAccountData.stakedAmount.toString()).to.equal(stakeAmount.toString());
     expect(stakeAccountData.owner.toString()).to.equal(user.publicKey.toString());
+    expect(stakeAccountData.positionIndex).to.equal(positionIndex);
   });
 
   it("Emergency pause and resume", async () => {